@@ -0,0 +1,48 @@
+//! Embed a snapshot of the linguist language colors into the binary, so that
+//! they are available without waiting on, or being able to reach, the network.
+//!
+//! The snapshot is regenerated from linguist's `languages.yml` by `scripts/update-colors.py`.
+
+use std::{collections::BTreeMap, env, fs, path::Path};
+
+const SNAPSHOT: &str = "assets/colors.json";
+
+fn main() {
+    println!("cargo:rerun-if-changed={SNAPSHOT}");
+
+    let snapshot = fs::read_to_string(SNAPSHOT).expect("failed to read color snapshot");
+    let languages: BTreeMap<String, serde_json::Value> =
+        serde_json::from_str(&snapshot).expect("failed to parse color snapshot");
+
+    let entries = languages
+        .iter()
+        .filter_map(|(language, value)| {
+            let color = value["color"].as_str()?;
+
+            assert!(
+                color.len() == 7 && color.starts_with('#'),
+                "{language} has a non-hex formatted color {color}"
+            );
+
+            let channel = |range| {
+                u8::from_str_radix(&color[range], 16)
+                    .unwrap_or_else(|_| panic!("{language} has a non-hex formatted color {color}"))
+            };
+
+            Some(format!(
+                "    ({language:?}, [{}, {}, {}]),\n",
+                channel(1..3),
+                channel(3..5),
+                channel(5..7)
+            ))
+        })
+        .collect::<String>();
+
+    let out = Path::new(&env::var("OUT_DIR").expect("OUT_DIR not set")).join("colors.rs");
+
+    fs::write(
+        out,
+        format!("pub static BUNDLED_COLORS: &[(&str, [u8; 3])] = &[\n{entries}];\n"),
+    )
+    .expect("failed to write bundled colors");
+}
@@ -0,0 +1,46 @@
+#!/usr/bin/env python3
+"""Regenerate assets/colors.json from the languages known to github-linguist.
+
+The snapshot keeps the shape of https://github.com/ozh/github-colors, which the
+site refreshes its colors from, so that build.rs can read either.
+
+Usage: scripts/update-colors.py [path or url of languages.yml]
+"""
+
+import json
+import sys
+import urllib.parse
+import urllib.request
+from pathlib import Path
+
+import yaml
+
+LANGUAGES = "https://raw.githubusercontent.com/github-linguist/linguist/master/lib/linguist/languages.yml"
+SNAPSHOT = Path(__file__).resolve().parent.parent / "assets" / "colors.json"
+
+
+def main():
+    source = sys.argv[1] if len(sys.argv) > 1 else LANGUAGES
+
+    if "://" in source:
+        with urllib.request.urlopen(source) as response:
+            languages = yaml.safe_load(response)
+    else:
+        with open(source, encoding="utf-8") as file:
+            languages = yaml.safe_load(file)
+
+    colors = {
+        name: {
+            "color": language.get("color"),
+            "url": "https://github.com/trending?l=" + urllib.parse.quote(name),
+        }
+        for name, language in sorted(languages.items())
+    }
+
+    SNAPSHOT.write_text(json.dumps(colors, indent=4, ensure_ascii=False) + "\n", encoding="utf-8")
+
+    print(f"wrote {len(colors)} languages to {SNAPSHOT}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,221 @@
+use std::collections::BTreeMap;
+
+use log::warn;
+use serde::Deserialize;
+
+use crate::{
+    gh::{self, GithubApiError},
+    registry::{self, Registry},
+};
+
+#[derive(Deserialize)]
+struct Manifest {
+    package: Option<ManifestPackage>,
+    workspace: Option<ManifestWorkspace>,
+    #[serde(default)]
+    features: BTreeMap<String, toml::Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct ManifestPackage {
+    name: String,
+    description: Option<toml::Value>,
+    edition: Option<toml::Value>,
+    rust_version: Option<toml::Value>,
+    publish: Option<toml::Value>,
+}
+
+#[derive(Deserialize)]
+struct ManifestWorkspace {
+    #[serde(default)]
+    members: Vec<String>,
+    #[serde(default)]
+    exclude: Vec<String>,
+    /// Values members can inherit with `key.workspace = true`
+    package: Option<BTreeMap<String, toml::Value>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CrateInfo {
+    /// Directory of the crate, relative to the repository root
+    pub path: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub edition: Option<String>,
+    pub rust_version: Option<String>,
+    pub features: Vec<String>,
+    /// Latest version published to crates.io
+    pub published: Option<String>,
+}
+
+impl CrateInfo {
+    pub fn docs_url(&self) -> Option<String> {
+        self.published
+            .as_ref()
+            .map(|version| format!("https://docs.rs/{}/{version}", self.name))
+    }
+}
+
+/// Fetch information on every crate in a repository's cargo workspace, or just
+/// the root crate if it is not a workspace.
+///
+/// Resolves to `None` if the repository has no `Cargo.toml` in its root.
+pub async fn fetch_crates(full_name: &str) -> Result<Option<Vec<CrateInfo>>, GithubApiError> {
+    let root = match fetch_manifest(full_name, "").await? {
+        Some(root) => root,
+        None => return Ok(None),
+    };
+
+    let mut crates = Vec::new();
+    let workspace_package = root
+        .workspace
+        .as_ref()
+        .and_then(|workspace| workspace.package.clone())
+        .unwrap_or_default();
+
+    let members = match &root.workspace {
+        Some(workspace) => expand_members(full_name, workspace).await?,
+        None => Vec::new(),
+    };
+
+    if let Some(info) = crate_info(full_name, "", root, &workspace_package).await {
+        crates.push(info);
+    }
+
+    for member in members {
+        if let Some(manifest) = fetch_manifest(full_name, &member).await? {
+            if let Some(info) = crate_info(full_name, &member, manifest, &workspace_package).await {
+                crates.push(info);
+            }
+        }
+    }
+
+    Ok(Some(crates))
+}
+
+async fn fetch_manifest(full_name: &str, path: &str) -> Result<Option<Manifest>, GithubApiError> {
+    let manifest_path = if path.is_empty() {
+        "Cargo.toml".to_string()
+    } else {
+        format!("{path}/Cargo.toml")
+    };
+
+    Ok(gh::fetch_file(full_name, &manifest_path)
+        .await?
+        .and_then(|content| match toml::from_slice(&content.decode()) {
+            Ok(manifest) => Some(manifest),
+            Err(error) => {
+                warn!("Failed to parse {manifest_path} in {full_name}: {error}");
+
+                None
+            }
+        }))
+}
+
+/// Resolve the workspace members to directories, expanding trailing `*` globs
+async fn expand_members(
+    full_name: &str,
+    workspace: &ManifestWorkspace,
+) -> Result<Vec<String>, GithubApiError> {
+    #[derive(Deserialize)]
+    struct Entry {
+        path: String,
+        #[serde(rename = "type")]
+        kind: String,
+    }
+
+    let mut members = Vec::new();
+
+    for member in &workspace.members {
+        let member = member.trim_end_matches('/');
+
+        match member.strip_suffix("/*") {
+            Some(directory) => {
+                let entries = match gh::fetch(&format!(
+                    "https://api.github.com/repos/{full_name}/contents/{directory}"
+                ))
+                .await
+                {
+                    Ok(response) => response
+                        .json::<Vec<Entry>>()
+                        .await
+                        .expect("received unexpected json content"),
+                    Err(GithubApiError::NotFound { .. }) => continue,
+                    Err(error) => return Err(error),
+                };
+
+                members.extend(
+                    entries
+                        .into_iter()
+                        .filter(|entry| entry.kind == "dir")
+                        .map(|entry| entry.path),
+                );
+            }
+            None if member.contains(&['*', '?', '['][..]) => {
+                warn!("Unsupported workspace member glob {member} in {full_name}");
+            }
+            None => members.push(member.to_string()),
+        }
+    }
+
+    members.retain(|member| !workspace.exclude.iter().any(|exclude| exclude == member));
+
+    Ok(members)
+}
+
+/// Whether the `publish` key of a manifest allows publishing to crates.io,
+/// which is not the case for `publish = false` or a list of registries without it
+pub fn publishable(publish: Option<&toml::Value>) -> bool {
+    match publish {
+        Some(toml::Value::Boolean(publish)) => *publish,
+        Some(toml::Value::Array(registries)) => registries
+            .iter()
+            .any(|registry| registry.as_str() == Some("crates-io")),
+        _ => true,
+    }
+}
+
+/// Collect the information on a crate, `None` for virtual manifests
+async fn crate_info(
+    full_name: &str,
+    path: &str,
+    manifest: Manifest,
+    workspace_package: &BTreeMap<String, toml::Value>,
+) -> Option<CrateInfo> {
+    let package = manifest.package?;
+
+    // Resolve `key.workspace = true` to the value in the workspace manifest
+    let resolve = |key: &str, value: Option<toml::Value>| -> Option<String> {
+        match value? {
+            toml::Value::String(value) => Some(value),
+            toml::Value::Table(table)
+                if table.get("workspace") == Some(&toml::Value::Boolean(true)) =>
+            {
+                workspace_package
+                    .get(key)
+                    .and_then(|value| value.as_str())
+                    .map(String::from)
+            }
+            _ => None,
+        }
+    };
+
+    let published = if publishable(package.publish.as_ref()) {
+        registry::query_registry(Registry::CratesIo, package.name.clone(), full_name)
+            .await
+            .map(|package| package.version)
+    } else {
+        None
+    };
+
+    Some(CrateInfo {
+        path: path.to_string(),
+        description: resolve("description", package.description),
+        edition: resolve("edition", package.edition),
+        rust_version: resolve("rust-version", package.rust_version),
+        features: manifest.features.into_keys().collect(),
+        published,
+        name: package.name,
+    })
+}
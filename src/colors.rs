@@ -0,0 +1,131 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::Rc,
+};
+
+use css_colors::{hsl, rgb, Color, RGB};
+use dioxus::prelude::ScopeState;
+use log::{debug, warn};
+
+use crate::{
+    config,
+    gh::{self, GithubApiError},
+    hook::use_subscription::{use_subscription, Subscribers},
+};
+
+// Generated by the build script from `assets/colors.json`
+include!(concat!(env!("OUT_DIR"), "/colors.rs"));
+
+type Colors = HashMap<String, RGB>;
+
+thread_local! {
+    static COLORS: RefCell<Rc<Colors>> = RefCell::new(Rc::new(bundled()));
+    static REFRESHED: Cell<bool> = Cell::new(false);
+    static SUBSCRIBERS: Subscribers = Subscribers::default();
+}
+
+fn bundled() -> Colors {
+    BUNDLED_COLORS
+        .iter()
+        .map(|&(language, [r, g, b])| (language.to_string(), rgb(r, g, b)))
+        .collect()
+}
+
+/// Re-render the component once the language colors have been refreshed
+pub fn use_colors(cx: &ScopeState) {
+    use_subscription(cx, &SUBSCRIBERS);
+}
+
+/// Get the color github uses for a language, if it has one
+pub fn language_color(language: &str) -> Option<RGB> {
+    COLORS.with(|colors| colors.borrow().get(language).copied())
+}
+
+/// Get a color for a language, which is always the same for the same language.
+///
+/// Languages without a color from github get one derived from their name, and
+/// repositories without a language get a neutral gray.
+pub fn color_for(language: Option<&str>) -> RGB {
+    match language {
+        Some(language) => language_color(language).unwrap_or_else(|| derived_color(language)),
+        None => hsl(0, 0, 35).to_rgb(),
+    }
+}
+
+/// Hash the name into a color that is neither too dull nor too loud
+fn derived_color(language: &str) -> RGB {
+    // FNV-1a, since the standard library's hasher is not stable across releases
+    let hash = language.bytes().fold(0x811c9dc5_u32, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x01000193)
+    });
+
+    let hue = hash % 360;
+    let saturation = 45 + (hash >> 9) % 20;
+    let lightness = 40 + (hash >> 17) % 15;
+
+    hsl(hue as i32, saturation as u8, lightness as u8).to_rgb()
+}
+
+/// Replace the bundled colors with the latest ones from the network.
+///
+/// Only the first call does anything, and failures, including any status other
+/// than success, are logged instead of returned since the bundled colors are
+/// always there to fall back on.
+pub async fn refresh() {
+    if !config::REFRESH_COLORS || REFRESHED.with(|refreshed| refreshed.replace(true)) {
+        return;
+    }
+
+    match fetch_colors().await {
+        Ok(colors) => {
+            debug!("Refreshed {} language colors", colors.len());
+
+            COLORS.with(|current| *current.borrow_mut() = Rc::new(colors));
+            SUBSCRIBERS.with(Subscribers::notify);
+        }
+        Err(_) => warn!("Failed to refresh language colors, using the bundled snapshot"),
+    }
+}
+
+async fn fetch_colors() -> Result<Colors, GithubApiError> {
+    let response =
+        gh::fetch("https://api.github.com/repos/ozh/github-colors/contents/colors.json").await?;
+
+    let json = response
+        .json::<gh::Content>()
+        .await
+        .map_err(GithubApiError::Net)?
+        .try_decode()
+        .ok_or_else(|| {
+            GithubApiError::Net(gloo_net::Error::GlooError(
+                "color information was not base64 encoded".to_string(),
+            ))
+        })?;
+
+    let colors: HashMap<String, serde_json::Value> = serde_json::from_slice(&json)
+        .map_err(|error| GithubApiError::Net(gloo_net::Error::SerdeError(error)))?;
+
+    Ok(colors
+        .into_iter()
+        .filter_map(|(key, value)| {
+            value["color"].as_str().and_then(|color| {
+                let color = parse_hex(color);
+
+                if color.is_none() {
+                    warn!("Received badly formatted color for {key}");
+                }
+
+                color.map(|color| (key, color))
+            })
+        })
+        .collect())
+}
+
+/// Parse a `#rrggbb` formatted color
+fn parse_hex(color: &str) -> Option<RGB> {
+    let hex = color.strip_prefix('#').filter(|hex| hex.len() == 6)?;
+    let channel = |start| u8::from_str_radix(hex.get(start..start + 2)?, 16).ok();
+
+    Some(rgb(channel(0)?, channel(2)?, channel(4)?))
+}
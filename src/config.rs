@@ -0,0 +1,112 @@
+//! Compile time configuration of which accounts are shown and how.
+
+/// Github accounts whose repositories are displayed, in display order
+pub const USERS: &[&str] = &["dusterthefirst", "thedustyard"];
+
+/// Account whose profile is shown in the title card
+pub const PROFILE_USER: &str = USERS[0];
+
+/// Values shown in the title card in place of those from the account's profile
+pub struct ProfileOverrides {
+    pub name: Option<&'static str>,
+    pub email: Option<&'static str>,
+    pub bio: Option<&'static str>,
+    pub blog: Option<&'static str>,
+}
+
+pub const PROFILE_OVERRIDES: ProfileOverrides = ProfileOverrides {
+    name: Some("Zachary Kohnen"),
+    email: Some("me@dusterthefirst.com"),
+    bio: None,
+    blog: Some("https://dusterthefirst.com"),
+};
+
+/// A link shown in the title card alongside those from the account's profile
+pub struct ProfileLink {
+    pub text: &'static str,
+    pub href: &'static str,
+    pub title: &'static str,
+    pub rel: &'static str,
+}
+
+pub const PROFILE_LINKS: &[ProfileLink] = &[ProfileLink {
+    text: "gh.dusterthefirst.com",
+    href: "https://gh.dusterthefirst.com",
+    title: "github portfolio",
+    rel: "canonical",
+}];
+
+/// Repositories shown as pinned when the pinned items can not be queried.
+///
+/// Github only exposes profile pins through the GraphQL api, which requires
+/// authentication, so this list is used in its place for unauthenticated visitors.
+pub const PINNED_FALLBACK: &[(&str, &[&str])] = &[
+    ("dusterthefirst", &["gh.dusterthefirst.com"]),
+    ("thedustyard", &[]),
+];
+
+/// Get the fallback pinned repository names for the given account
+pub fn pinned_fallback(user: &str) -> &'static [&'static str] {
+    PINNED_FALLBACK
+        .iter()
+        .find(|(account, _)| *account == user)
+        .map(|(_, repos)| *repos)
+        .unwrap_or_default()
+}
+
+/// Refresh the bundled language colors from the network once the page has loaded
+pub const REFRESH_COLORS: bool = true;
+
+/// Fetch the latest release of each repository once its card is on screen.
+///
+/// This costs one or two requests per repository.
+pub const FETCH_RELEASES: bool = true;
+
+/// Fetch the upstream of each forked repository once its card is on screen,
+/// along with how far the fork has diverged from it.
+pub const FETCH_FORKS: bool = true;
+
+/// Requests to github a session may make before cards stop fetching their details.
+///
+/// The unauthenticated rate limit is 60 requests an hour, so this leaves room
+/// for the repository lists, a reload and anything the visitor opens themselves.
+pub const REQUEST_BUDGET: u32 = 40;
+
+/// [`REQUEST_BUDGET`] for authenticated requests, which are limited to 5000 an hour
+pub const AUTHENTICATED_REQUEST_BUDGET: u32 = 4_000;
+
+/// How many cards may be fetching their details at once
+pub const ENRICHMENT_CONCURRENCY: usize = 4;
+
+/// Repository lists longer than this only render the cards near the viewport
+pub const WINDOW_THRESHOLD: usize = 100;
+
+/// Show the top contributors of each repository on its card
+pub const SHOW_CONTRIBUTORS: bool = true;
+
+/// How many of the top contributors to show
+pub const CONTRIBUTORS: u32 = 5;
+
+/// Base url of the crates.io api, overridable at compile time with `CRATES_IO_API`
+pub const CRATES_IO_API: &str = match option_env!("CRATES_IO_API") {
+    Some(url) => url,
+    None => "https://crates.io/api/v1",
+};
+
+/// Base url of the npm registry, overridable at compile time with `NPM_REGISTRY`
+pub const NPM_REGISTRY: &str = match option_env!("NPM_REGISTRY") {
+    Some(url) => url,
+    None => "https://registry.npmjs.org",
+};
+
+/// Base url of the npm download counts api, overridable at compile time with `NPM_DOWNLOADS_API`
+pub const NPM_DOWNLOADS_API: &str = match option_env!("NPM_DOWNLOADS_API") {
+    Some(url) => url,
+    None => "https://api.npmjs.org",
+};
+
+/// Base url of the PyPI json api, overridable at compile time with `PYPI_API`
+pub const PYPI_API: &str = match option_env!("PYPI_API") {
+    Some(url) => url,
+    None => "https://pypi.org/pypi",
+};
@@ -0,0 +1,292 @@
+//! Scheduling of the requests that fill in the details of repository cards.
+//!
+//! Cards only make their requests once they have been on screen, cards that are
+//! currently on screen are served first, and nothing more is requested once the
+//! session has used up its request budget. Results are cached per repository, as
+//! cards are dropped and created again as they scroll out of and into long lists.
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::{BTreeMap, HashMap},
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    task::{Context, Poll, Waker},
+    thread::LocalKey,
+};
+
+use crate::{
+    config,
+    gh::{self, GithubApiError},
+};
+
+struct TicketState {
+    /// Cards created earlier, which are higher on the page, go first
+    id: usize,
+    /// Whether the card is on screen
+    visible: Cell<bool>,
+    /// Whether the card has ever been on screen
+    seen: Cell<bool>,
+}
+
+/// A card's place in the queue, shared by all of its requests
+#[derive(Clone)]
+pub struct Ticket(Rc<TicketState>);
+
+struct Waiting {
+    ticket: Rc<TicketState>,
+    waker: Waker,
+}
+
+#[derive(Default)]
+struct Scheduler {
+    /// Requests waiting for their turn, keyed by the order they were made in
+    waiting: BTreeMap<usize, Waiting>,
+    running: usize,
+    next_ticket: usize,
+    next_request: usize,
+}
+
+thread_local! {
+    static SCHEDULER: RefCell<Scheduler> = RefCell::new(Scheduler::default());
+}
+
+fn budget() -> u32 {
+    if gh::authenticated() {
+        config::AUTHENTICATED_REQUEST_BUDGET
+    } else {
+        config::REQUEST_BUDGET
+    }
+}
+
+/// Let every waiting request check whether it is their turn
+fn wake_all() {
+    let wakers = SCHEDULER.with(|scheduler| {
+        scheduler
+            .borrow()
+            .waiting
+            .values()
+            .map(|waiting| waiting.waker.clone())
+            .collect::<Vec<_>>()
+    });
+
+    for waker in wakers {
+        waker.wake();
+    }
+}
+
+impl Ticket {
+    /// A ticket for a card that has not been on screen yet
+    pub fn offscreen() -> Self {
+        let id = SCHEDULER.with(|scheduler| {
+            let mut scheduler = scheduler.borrow_mut();
+            scheduler.next_ticket += 1;
+
+            scheduler.next_ticket
+        });
+
+        Self(Rc::new(TicketState {
+            id,
+            visible: Cell::new(false),
+            seen: Cell::new(false),
+        }))
+    }
+
+    /// A ticket that is always on screen, for pages showing a single repository
+    pub fn visible() -> Self {
+        let ticket = Self::offscreen();
+        ticket.set_visible(true);
+
+        ticket
+    }
+
+    pub fn set_visible(&self, visible: bool) {
+        if visible {
+            self.0.seen.set(true);
+        }
+
+        if self.0.visible.replace(visible) != visible {
+            wake_all();
+        }
+    }
+
+    /// Wait for the card's turn, then make the request.
+    ///
+    /// Fails with [`GithubApiError::BudgetExhausted`] once the session has run
+    /// out of requests, whether before the request starts or part way through it.
+    /// The card's place is held until the request finishes, so a request should
+    /// not wait around inside it.
+    pub async fn run<T>(
+        &self,
+        request: impl Future<Output = Result<T, GithubApiError>>,
+    ) -> Result<T, GithubApiError> {
+        let id = SCHEDULER.with(|scheduler| {
+            let mut scheduler = scheduler.borrow_mut();
+            scheduler.next_request += 1;
+
+            scheduler.next_request
+        });
+
+        Acquire {
+            ticket: self.0.clone(),
+            id,
+        }
+        .await?;
+
+        let _slot = Slot;
+
+        Budgeted {
+            request: Box::pin(request),
+        }
+        .await
+    }
+}
+
+/// Successful results of one kind of request, keyed by the repository's full name
+pub struct Cache<T>(RefCell<HashMap<String, T>>);
+
+impl<T> Default for Cache<T> {
+    fn default() -> Self {
+        Self(RefCell::new(HashMap::new()))
+    }
+}
+
+/// Resolve to the cached result for the repository, or make the request and cache
+/// its result if it succeeds
+pub async fn cached<T: Clone>(
+    cache: &'static LocalKey<Cache<T>>,
+    full_name: &str,
+    request: impl Future<Output = Result<T, GithubApiError>>,
+) -> Result<T, GithubApiError> {
+    cached_if(cache, full_name, request, |_| true).await
+}
+
+/// Like [`cached`], but resolving to the default if the request fails.
+///
+/// For the details of a card, which are extras that are not worth showing an
+/// error in place of the card over.
+pub async fn cached_or_default<T: Clone + Default>(
+    cache: &'static LocalKey<Cache<T>>,
+    full_name: &str,
+    request: impl Future<Output = Result<T, GithubApiError>>,
+) -> T {
+    cached(cache, full_name, request).await.unwrap_or_default()
+}
+
+/// Like [`cached`], but only caching the results that are final according to `keep`
+pub async fn cached_if<T: Clone>(
+    cache: &'static LocalKey<Cache<T>>,
+    full_name: &str,
+    request: impl Future<Output = Result<T, GithubApiError>>,
+    keep: impl FnOnce(&T) -> bool,
+) -> Result<T, GithubApiError> {
+    if let Some(cached) = cache.with(|cache| cache.0.borrow().get(full_name).cloned()) {
+        return Ok(cached);
+    }
+
+    let result = request.await?;
+
+    if keep(&result) {
+        cache.with(|cache| {
+            cache
+                .0
+                .borrow_mut()
+                .insert(full_name.to_string(), result.clone())
+        });
+    }
+
+    Ok(result)
+}
+
+/// Order in which waiting requests are served, lowest first
+fn priority(ticket: &TicketState, request: usize) -> (bool, usize, usize) {
+    (!ticket.visible.get(), ticket.id, request)
+}
+
+/// Resolves once it is the request's turn to run
+struct Acquire {
+    ticket: Rc<TicketState>,
+    id: usize,
+}
+
+impl Future for Acquire {
+    type Output = Result<(), GithubApiError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let granted = SCHEDULER.with(|scheduler| {
+            let mut scheduler = scheduler.borrow_mut();
+
+            if gh::requests_sent() >= budget() {
+                scheduler.waiting.remove(&self.id);
+
+                return Some(Err(GithubApiError::BudgetExhausted));
+            }
+
+            scheduler.waiting.insert(
+                self.id,
+                Waiting {
+                    ticket: self.ticket.clone(),
+                    waker: cx.waker().clone(),
+                },
+            );
+
+            let next = scheduler
+                .waiting
+                .iter()
+                .filter(|(_, waiting)| waiting.ticket.seen.get())
+                .min_by_key(|(id, waiting)| priority(&waiting.ticket, **id))
+                .map(|(id, _)| *id);
+
+            if scheduler.running < config::ENRICHMENT_CONCURRENCY && next == Some(self.id) {
+                scheduler.waiting.remove(&self.id);
+                scheduler.running += 1;
+
+                Some(Ok(()))
+            } else {
+                None
+            }
+        });
+
+        match granted {
+            Some(Ok(())) => {
+                // There may be room for the next request in line too
+                wake_all();
+
+                Poll::Ready(Ok(()))
+            }
+            Some(Err(error)) => Poll::Ready(Err(error)),
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl Drop for Acquire {
+    fn drop(&mut self) {
+        // The card went away before its turn came
+        SCHEDULER.with(|scheduler| scheduler.borrow_mut().waiting.remove(&self.id));
+    }
+}
+
+/// Limits the github requests sent while polling the request to the budget
+struct Budgeted<F> {
+    request: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for Budgeted<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        gh::limit_requests(budget(), || self.request.as_mut().poll(cx))
+    }
+}
+
+/// Frees up a place for the next request once dropped
+struct Slot;
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        SCHEDULER.with(|scheduler| scheduler.borrow_mut().running -= 1);
+
+        wake_all();
+    }
+}
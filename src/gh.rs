@@ -1,11 +1,62 @@
+use std::{
+    cell::Cell,
+    collections::HashMap,
+    sync::atomic::{AtomicU32, Ordering},
+};
+
 use gloo_net::http::{Request, RequestCache, Response};
-use log::{error, warn};
+use log::{debug, error, trace, warn};
 use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{de::DeserializeOwned, Deserialize};
 use time::{Date, OffsetDateTime};
 
 pub enum GithubApiError {
-    RateLimited { until: OffsetDateTime },
+    RateLimited {
+        until: OffsetDateTime,
+    },
+    NotFound {
+        url: String,
+    },
     Net(gloo_net::Error),
+    BudgetExhausted,
+    /// Github answered with a status code that is not otherwise handled
+    Unexpected {
+        status: u16,
+    },
+}
+
+/// A file as returned from the repository contents api
+#[derive(Deserialize, Debug)]
+pub struct Content {
+    pub path: String,
+    pub html_url: String,
+    pub download_url: Option<String>,
+    encoding: String,
+    content: String,
+}
+
+impl Content {
+    /// Decode the base64 encoded file contents
+    pub fn decode(&self) -> Vec<u8> {
+        self.try_decode()
+            .expect("content was not encoded as valid base64")
+    }
+
+    /// Decode the base64 encoded file contents, if they are
+    pub fn try_decode(&self) -> Option<Vec<u8>> {
+        if self.encoding != "base64" {
+            return None;
+        }
+
+        base64::decode(
+            self.content
+                .bytes()
+                .filter(|&byte| byte != b'\n')
+                .collect::<Vec<_>>(),
+        )
+        .ok()
+    }
 }
 
 #[cfg(debug_assertions)]
@@ -39,11 +90,210 @@ pub const AUTH_LOCAL_STORAGE_KEY: Option<&str> = None;
 #[cfg(not(debug_assertions))]
 static PERSONAL_ACCESS_TOKEN: Lazy<Option<String>> = Lazy::new(|| None);
 
-pub async fn fetch(url: &str) -> Result<Response, GithubApiError> {
-    let request = Request::get(url)
+/// Fetch a file from a repository, resolving to `None` if it does not exist
+pub async fn fetch_file(full_name: &str, path: &str) -> Result<Option<Content>, GithubApiError> {
+    match fetch(&format!(
+        "https://api.github.com/repos/{full_name}/contents/{path}"
+    ))
+    .await
+    {
+        Ok(response) => Ok(Some(
+            response
+                .json::<Content>()
+                .await
+                .expect("received unexpected json content"),
+        )),
+        Err(GithubApiError::NotFound { .. }) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Requests sent to github this session
+static REQUESTS_SENT: AtomicU32 = AtomicU32::new(0);
+
+thread_local! {
+    /// How many requests the session may send in total, while polling requests
+    /// that are limited by a budget
+    static REQUEST_LIMIT: Cell<Option<u32>> = Cell::new(None);
+}
+
+/// How many requests have been sent to github this session
+pub fn requests_sent() -> u32 {
+    REQUESTS_SENT.load(Ordering::Relaxed)
+}
+
+/// Fail every request sent from within `poll` with [`GithubApiError::BudgetExhausted`]
+/// once the session has sent `limit` requests
+pub fn limit_requests<R>(limit: u32, poll: impl FnOnce() -> R) -> R {
+    let previous = REQUEST_LIMIT.with(|current| current.replace(Some(limit)));
+    let result = poll();
+    REQUEST_LIMIT.with(|current| current.set(previous));
+
+    result
+}
+
+/// Check if requests to the github api will be authenticated
+pub fn authenticated() -> bool {
+    PERSONAL_ACCESS_TOKEN.is_some()
+}
+
+fn get(url: &str) -> Request {
+    Request::get(url)
         .header("accept", "application/vnd.github.v3+json")
-        .cache(RequestCache::Default);
+        .cache(RequestCache::Default)
+}
+
+pub async fn fetch(url: &str) -> Result<Response, GithubApiError> {
+    send(get(url)).await
+}
+
+/// Fetch from an endpoint that answers with `204` when there is nothing to return,
+/// resolving to `None` in that case
+pub async fn fetch_unless_empty(url: &str) -> Result<Option<Response>, GithubApiError> {
+    let response = send_accepting(get(url), &[200, 204]).await?;
+
+    Ok(Some(response).filter(|response| response.status() != 204))
+}
+
+/// The answer of one of the repository statistics endpoints
+pub enum Statistics {
+    Ready(Response),
+    /// Github is still computing the statistics and should be asked again later
+    Computing,
+    /// The repository has no statistics, ie it is empty
+    Empty,
+}
+
+/// Fetch from one of the repository statistics endpoints, which answer with `202`
+/// while the statistics are being computed and `204` when there are none
+pub async fn fetch_statistics(url: &str) -> Result<Statistics, GithubApiError> {
+    let response = send_accepting(get(url), &[200, 202, 204]).await?;
+
+    Ok(match response.status() {
+        202 => Statistics::Computing,
+        204 => Statistics::Empty,
+        _ => Statistics::Ready(response),
+    })
+}
+
+/// Fetch every page of a paginated list, following the `link` header
+pub async fn fetch_all_pages<T: DeserializeOwned>(
+    mut url: String,
+) -> Result<Vec<T>, GithubApiError> {
+    let mut items = Vec::new();
+
+    loop {
+        let response = fetch(&url).await?;
 
+        items.extend(
+            response
+                .json::<Vec<T>>()
+                .await
+                .expect("received unexpected json content"),
+        );
+
+        match next_page(&response) {
+            Some(next) => url = next,
+            None => break,
+        }
+    }
+
+    Ok(items)
+}
+
+/// The url of the page after the response's, following the `link` header
+pub fn next_page(response: &Response) -> Option<String> {
+    let url = response.url();
+
+    let link = match response.headers().get("link") {
+        Some(link) => link,
+        None => {
+            debug!("No pagination for {url}");
+            return None;
+        }
+    };
+
+    static REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new("<(?P<url>.+?)>; rel=\"(?P<rel>.+?)\"").expect("invalid regex"));
+
+    let captures = REGEX
+        .captures_iter(&link)
+        .map(|captures| {
+            (
+                captures.name("rel").expect("no `rel` group").as_str(),
+                captures.name("url").expect("no `url` group").as_str(),
+            )
+        })
+        .collect::<HashMap<_, _>>();
+
+    match captures.get("next") {
+        Some(next) => {
+            trace!("paginating to next {next}");
+
+            Some(next.to_string())
+        }
+        None => {
+            debug!("Reached end of pagination for {url}");
+
+            None
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct GraphqlResponse<T> {
+    data: Option<T>,
+    #[serde(default)]
+    errors: Vec<GraphqlError>,
+}
+
+#[derive(Deserialize)]
+struct GraphqlError {
+    message: String,
+}
+
+/// Run a query against the github GraphQL api.
+///
+/// The GraphQL api does not allow unauthenticated access, so `None` is returned
+/// when no personal access token is available or the query produced no data.
+pub async fn graphql<T: DeserializeOwned>(
+    query: &str,
+    variables: serde_json::Value,
+) -> Result<Option<T>, GithubApiError> {
+    if !authenticated() {
+        return Ok(None);
+    }
+
+    let body = serde_json::json!({
+        "query": query,
+        "variables": variables,
+    });
+
+    let response = send(
+        Request::post("https://api.github.com/graphql")
+            .header("content-type", "application/json")
+            .body(body.to_string()),
+    )
+    .await?;
+
+    let response = response
+        .json::<GraphqlResponse<T>>()
+        .await
+        .expect("received unexpected graphql response");
+
+    for error in &response.errors {
+        error!("GraphQL query failed: {}", error.message);
+    }
+
+    Ok(response.data)
+}
+
+async fn send(request: Request) -> Result<Response, GithubApiError> {
+    send_accepting(request, &[200]).await
+}
+
+/// Send a request, treating the `successful` status codes as success
+async fn send_accepting(request: Request, successful: &[u16]) -> Result<Response, GithubApiError> {
     // Attach personal access token if one provided
     let request = if let Some(token) = PERSONAL_ACCESS_TOKEN.as_ref() {
         request.header("Authorization", &format!("token {token}"))
@@ -51,13 +301,24 @@ pub async fn fetch(url: &str) -> Result<Response, GithubApiError> {
         request
     };
 
+    if REQUEST_LIMIT
+        .with(Cell::get)
+        .map_or(false, |limit| requests_sent() >= limit)
+    {
+        return Err(GithubApiError::BudgetExhausted);
+    }
+
+    REQUESTS_SENT.fetch_add(1, Ordering::Relaxed);
+
     let response = request.send().await.map_err(GithubApiError::Net)?;
 
     let headers = response.headers();
 
     match response.status() {
-        200 => {}
-        403 => {
+        code if successful.contains(&code) => {}
+        // Forbidden is also used for requests github refuses to answer, such as
+        // contributors of repositories with too large a history
+        403 if headers.get("x-ratelimit-remaining").as_deref() == Some("0") => {
             let until = OffsetDateTime::from_unix_timestamp(
                 headers
                     .get("x-ratelimit-reset")
@@ -72,6 +333,13 @@ pub async fn fetch(url: &str) -> Result<Response, GithubApiError> {
 
             return Err(GithubApiError::RateLimited { until });
         }
+        404 => {
+            warn!("Github could not find {}", response.url());
+
+            return Err(GithubApiError::NotFound {
+                url: response.url(),
+            });
+        }
         401 => {
             error!("Provided personal access token was rejected by github");
 
@@ -80,25 +348,26 @@ pub async fn fetch(url: &str) -> Result<Response, GithubApiError> {
                 until: Date::MAX.midnight().assume_utc(),
             });
         }
-        code => unimplemented!("Encountered unknown http status code: {code}"),
+        status => {
+            error!("Github answered {} with {status}", response.url());
+
+            return Err(GithubApiError::Unexpected { status });
+        }
     }
 
-    let remaining: u32 = headers
-        .get("x-ratelimit-remaining")
-        .expect("x-ratelimit-remaining header missing")
-        .as_str()
-        .parse()
-        .expect("x-ratelimit-remaining provided as a non-integer");
-
-    if remaining < 10 {
-        let limit: u32 = headers
-            .get("x-ratelimit-limit")
-            .expect("x-ratelimit-limit header missing")
-            .as_str()
-            .parse()
-            .expect("x-ratelimit-limit provided as a non-integer");
-
-        warn!("Getting close to the rate limit {remaining}/{limit} requests left");
+    // Only a warning, so a response without the headers is no reason to fail
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.parse::<u32>().ok())
+    };
+
+    if let (Some(remaining), Some(limit)) =
+        (header("x-ratelimit-remaining"), header("x-ratelimit-limit"))
+    {
+        if remaining < 10 {
+            warn!("Getting close to the rate limit {remaining}/{limit} requests left");
+        }
     }
 
     Ok(response)
@@ -1,2 +1,39 @@
+use dioxus::prelude::UseFuture;
+
+use self::use_repos::RefetchFn;
+
+pub mod use_ci_status;
+pub mod use_commit_activity;
+pub mod use_contributions;
+pub mod use_contributors;
+pub mod use_crates;
+pub mod use_enrichment;
+pub mod use_events;
+pub mod use_external_contributions;
+pub mod use_fork;
+pub mod use_gist_preview;
+pub mod use_intersection;
+pub mod use_languages;
+pub mod use_latest_release;
+pub mod use_packages;
+pub mod use_profile;
+pub mod use_readme;
+pub mod use_releases;
+pub mod use_repo_language;
 pub mod use_repos;
-pub mod use_viewport;
+pub mod use_subscription;
+pub mod use_viewport_size;
+pub mod use_windowing;
+
+/// Restart a future, clearing its result so it shows as loading again
+pub fn refetch<T>(future: &UseFuture<T>) -> RefetchFn<'_> {
+    Box::new(|| {
+        future.clear();
+        future.restart();
+    })
+}
+
+/// A future's result, along with a way to fetch it again for the retry button of errors
+pub fn with_refetch<T>(future: &UseFuture<T>) -> Option<(&T, RefetchFn<'_>)> {
+    future.value().map(|res| (res, refetch(future)))
+}
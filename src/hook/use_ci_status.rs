@@ -0,0 +1,92 @@
+use dioxus::prelude::*;
+use serde::Deserialize;
+use time::OffsetDateTime;
+
+use crate::{
+    enrich::{self, Cache, Ticket},
+    gh::{self, GithubApiError},
+    hook::use_repos::Repo,
+};
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct WorkflowRun {
+    pub name: Option<String>,
+    pub html_url: String,
+    pub status: Option<String>,
+    pub conclusion: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub run_started_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CiStatus {
+    Passing,
+    Failing,
+    InProgress,
+    /// Cancelled, skipped or otherwise inconclusive
+    Neutral,
+}
+
+impl WorkflowRun {
+    pub fn ci_status(&self) -> CiStatus {
+        match (self.status.as_deref(), self.conclusion.as_deref()) {
+            (Some("completed"), Some("success")) => CiStatus::Passing,
+            (Some("completed"), Some("failure" | "timed_out" | "startup_failure")) => {
+                CiStatus::Failing
+            }
+            (Some("completed"), _) => CiStatus::Neutral,
+            _ => CiStatus::InProgress,
+        }
+    }
+}
+
+thread_local! {
+    static CACHE: Cache<Option<WorkflowRun>> = Cache::default();
+}
+
+/// Fetch the most recent workflow run on the default branch of a repository.
+///
+/// Resolves to `None` for repositories without github actions, or if the run
+/// could not be fetched.
+pub fn use_ci_status<'state>(
+    cx: &'state ScopeState,
+    repo: &Repo,
+    ticket: &Ticket,
+) -> Option<&'state Option<WorkflowRun>> {
+    let full_name = repo.full_name.clone();
+    let default_branch = repo.default_branch.clone();
+    let ticket = ticket.clone();
+
+    let future = use_future(cx, (), move |()| async move {
+        let request = ticket.run(fetch_latest_run(&full_name, &default_branch));
+
+        enrich::cached_or_default(&CACHE, &full_name, request).await
+    });
+
+    future.value()
+}
+
+async fn fetch_latest_run(
+    full_name: &str,
+    default_branch: &str,
+) -> Result<Option<WorkflowRun>, GithubApiError> {
+    #[derive(Deserialize)]
+    struct WorkflowRuns {
+        workflow_runs: Vec<WorkflowRun>,
+    }
+
+    // Branch names may contain characters with a meaning in query strings
+    let branch = String::from(js_sys::encode_uri_component(default_branch));
+
+    let runs = gh::fetch(&format!(
+        "https://api.github.com/repos/{full_name}/actions/runs?branch={branch}&per_page=1"
+    ))
+    .await?
+    .json::<WorkflowRuns>()
+    .await
+    .expect("received unexpected json content");
+
+    Ok(runs.workflow_runs.into_iter().next())
+}
@@ -0,0 +1,99 @@
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use log::{debug, warn};
+use serde::Deserialize;
+
+use crate::{
+    enrich::{self, Cache, Ticket},
+    gh::{self, GithubApiError, Statistics},
+    hook::use_repos::Repo,
+};
+
+/// How many times to ask for the statistics while github is computing them
+const ATTEMPTS: u32 = 3;
+/// Fewer attempts are made without a token, as each comes out of a small budget
+const UNAUTHENTICATED_ATTEMPTS: u32 = 2;
+/// How long to wait between attempts, in milliseconds
+const RETRY_DELAY: u32 = 3_000;
+
+#[derive(Deserialize, Debug)]
+struct WeekActivity {
+    total: u32,
+}
+
+#[derive(Debug, Clone)]
+pub enum Activity {
+    /// Commits per week over the last year, oldest first
+    Weeks(Vec<u32>),
+    /// Github has no statistics for the repository
+    Missing,
+    /// Github was still computing the statistics after every attempt
+    Computing,
+}
+
+thread_local! {
+    static CACHE: Cache<Activity> = Cache::default();
+}
+
+/// Fetch the weekly commit counts of the last year for a repository.
+///
+/// Statistics that were still being computed are not cached, so the card asks
+/// again the next time it is rendered.
+pub fn use_commit_activity<'state>(
+    cx: &'state ScopeState,
+    repo: &Repo,
+    ticket: &Ticket,
+) -> Option<&'state Result<Activity, GithubApiError>> {
+    let full_name = repo.full_name.clone();
+    let ticket = ticket.clone();
+
+    let future = use_future(cx, (), move |()| async move {
+        let request = fetch_commit_activity(&full_name, &ticket);
+
+        enrich::cached_if(&CACHE, &full_name, request, |activity| {
+            !matches!(activity, Activity::Computing)
+        })
+        .await
+    });
+
+    future.value()
+}
+
+async fn fetch_commit_activity(
+    full_name: &str,
+    ticket: &Ticket,
+) -> Result<Activity, GithubApiError> {
+    let url = format!("https://api.github.com/repos/{full_name}/stats/commit_activity");
+    let attempts = if gh::authenticated() {
+        ATTEMPTS
+    } else {
+        UNAUTHENTICATED_ATTEMPTS
+    };
+
+    for attempt in 1..=attempts {
+        // Each attempt takes its own turn, so other cards go ahead while this one waits
+        match ticket.run(gh::fetch_statistics(&url)).await? {
+            Statistics::Computing if attempt < attempts => {
+                debug!("Commit activity for {full_name} is being computed (attempt {attempt}/{attempts})");
+
+                TimeoutFuture::new(RETRY_DELAY * attempt).await;
+            }
+            Statistics::Computing => {}
+            Statistics::Empty => return Ok(Activity::Missing),
+            Statistics::Ready(response) => {
+                let weeks = response
+                    .json::<Vec<WeekActivity>>()
+                    .await
+                    .expect("received unexpected json content");
+
+                return Ok(Activity::Weeks(
+                    weeks.into_iter().map(|week| week.total).collect(),
+                ));
+            }
+        }
+    }
+
+    warn!("Gave up waiting for github to compute commit activity for {full_name}");
+
+    Ok(Activity::Computing)
+}
@@ -0,0 +1,169 @@
+use dioxus::prelude::*;
+use serde::Deserialize;
+
+use crate::gh::{self, GithubApiError};
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributionDay {
+    pub date: String,
+    pub weekday: u8,
+    pub contribution_count: u32,
+    pub contribution_level: ContributionLevel,
+}
+
+/// How a day's contributions compare to the rest of the calendar's
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ContributionLevel {
+    None,
+    FirstQuartile,
+    SecondQuartile,
+    ThirdQuartile,
+    FourthQuartile,
+}
+
+impl ContributionLevel {
+    /// From 0 for no contributions to 4 for the most
+    pub fn index(self) -> u8 {
+        match self {
+            ContributionLevel::None => 0,
+            ContributionLevel::FirstQuartile => 1,
+            ContributionLevel::SecondQuartile => 2,
+            ContributionLevel::ThirdQuartile => 3,
+            ContributionLevel::FourthQuartile => 4,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributionWeek {
+    pub contribution_days: Vec<ContributionDay>,
+}
+
+#[derive(Debug)]
+pub struct Contributions {
+    /// Years the account has contributions in, most recent first
+    pub years: Vec<i32>,
+    pub weeks: Vec<ContributionWeek>,
+}
+
+impl Contributions {
+    pub fn days(&self) -> impl Iterator<Item = &ContributionDay> {
+        self.weeks
+            .iter()
+            .flat_map(|week| week.contribution_days.iter())
+    }
+
+    pub fn total(&self) -> u32 {
+        self.days().map(|day| day.contribution_count).sum()
+    }
+
+    /// The most consecutive days with contributions
+    pub fn longest_streak(&self) -> u32 {
+        self.days()
+            .fold((0, 0), |(longest, current), day| {
+                if day.contribution_count > 0 {
+                    (longest.max(current + 1), current + 1)
+                } else {
+                    (longest, 0)
+                }
+            })
+            .0
+    }
+
+    /// The consecutive days with contributions leading up to the last day
+    pub fn current_streak(&self) -> u32 {
+        let mut days = self.days().collect::<Vec<_>>();
+
+        // The last day has not ended yet, so do not break the streak over it
+        if matches!(days.last(), Some(day) if day.contribution_count == 0) {
+            days.pop();
+        }
+
+        days.iter()
+            .rev()
+            .take_while(|day| day.contribution_count > 0)
+            .count() as u32
+    }
+}
+
+/// Fetch the contribution calendar of an account, for the given year or the
+/// last year if `None`.
+///
+/// Resolves to `Ok(None)` if the calendar is not available, which is the case
+/// for organizations and unauthenticated visitors.
+pub fn use_contributions(
+    cx: &ScopeState,
+    user: &'static str,
+    year: Option<i32>,
+) -> Option<&Result<Option<Contributions>, GithubApiError>> {
+    let future = use_future(cx, (year,), move |(year,)| async move {
+        fetch_contributions(user, year).await
+    });
+
+    future.value()
+}
+
+async fn fetch_contributions(
+    user: &str,
+    year: Option<i32>,
+) -> Result<Option<Contributions>, GithubApiError> {
+    #[derive(Deserialize)]
+    struct Data {
+        user: Option<User>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct User {
+        contributions_collection: ContributionsCollection,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct ContributionsCollection {
+        contribution_years: Vec<i32>,
+        contribution_calendar: ContributionCalendar,
+    }
+
+    #[derive(Deserialize)]
+    struct ContributionCalendar {
+        weeks: Vec<ContributionWeek>,
+    }
+
+    let (from, to) = match year {
+        Some(year) => (
+            Some(format!("{year}-01-01T00:00:00Z")),
+            Some(format!("{year}-12-31T23:59:59Z")),
+        ),
+        None => (None, None),
+    };
+
+    let data = gh::graphql::<Data>(
+        r#"query($login: String!, $from: DateTime, $to: DateTime) {
+            user(login: $login) {
+                contributionsCollection(from: $from, to: $to) {
+                    contributionYears
+                    contributionCalendar {
+                        weeks {
+                            contributionDays { date weekday contributionCount contributionLevel }
+                        }
+                    }
+                }
+            }
+        }"#,
+        serde_json::json!({ "login": user, "from": from, "to": to }),
+    )
+    .await?;
+
+    Ok(data.and_then(|data| data.user).map(|user| {
+        let collection = user.contributions_collection;
+
+        Contributions {
+            years: collection.contribution_years,
+            weeks: collection.contribution_calendar.weeks,
+        }
+    }))
+}
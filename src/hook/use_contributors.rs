@@ -0,0 +1,59 @@
+use dioxus::prelude::*;
+use serde::Deserialize;
+
+use crate::{
+    config,
+    enrich::{self, Cache, Ticket},
+    gh::{self, GithubApiError},
+    hook::use_repos::Repo,
+};
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Contributor {
+    pub login: String,
+    pub avatar_url: String,
+    pub html_url: String,
+    pub contributions: u32,
+}
+
+thread_local! {
+    static CACHE: Cache<Vec<Contributor>> = Cache::default();
+}
+
+/// Fetch the top contributors of a repository, most commits first.
+///
+/// Resolves to an empty list if they could not be fetched.
+pub fn use_contributors<'state>(
+    cx: &'state ScopeState,
+    repo: &Repo,
+    ticket: &Ticket,
+) -> Option<&'state Vec<Contributor>> {
+    let full_name = repo.full_name.clone();
+    let ticket = ticket.clone();
+
+    let future = use_future(cx, (), move |()| async move {
+        let request = ticket.run(fetch_contributors(&full_name));
+
+        enrich::cached_or_default(&CACHE, &full_name, request).await
+    });
+
+    future.value()
+}
+
+async fn fetch_contributors(full_name: &str) -> Result<Vec<Contributor>, GithubApiError> {
+    let response = match gh::fetch_unless_empty(&format!(
+        "https://api.github.com/repos/{full_name}/contributors?per_page={}",
+        config::CONTRIBUTORS
+    ))
+    .await?
+    {
+        Some(response) => response,
+        // Empty repositories have no contributors
+        None => return Ok(Vec::new()),
+    };
+
+    Ok(response
+        .json::<Vec<Contributor>>()
+        .await
+        .expect("received unexpected json content"))
+}
@@ -0,0 +1,34 @@
+use dioxus::prelude::*;
+
+use crate::{
+    cargo::{self, CrateInfo},
+    enrich::{self, Cache, Ticket},
+    gh::GithubApiError,
+    hook::use_repos::{RefetchFn, Repo},
+};
+
+thread_local! {
+    static CACHE: Cache<Option<Vec<CrateInfo>>> = Cache::default();
+}
+
+/// Fetch the crates in a repository's cargo workspace, resolving to `Ok(None)`
+/// if the repository is not a cargo project
+pub fn use_crates<'state>(
+    cx: &'state ScopeState,
+    repo: &Repo,
+    ticket: &Ticket,
+) -> Option<(
+    &'state Result<Option<Vec<CrateInfo>>, GithubApiError>,
+    RefetchFn<'state>,
+)> {
+    let full_name = repo.full_name.clone();
+    let ticket = ticket.clone();
+
+    let future = use_future(cx, (), move |()| async move {
+        let request = ticket.run(cargo::fetch_crates(&full_name));
+
+        enrich::cached(&CACHE, &full_name, request).await
+    });
+
+    super::with_refetch(future)
+}
@@ -0,0 +1,26 @@
+use dioxus::prelude::*;
+
+use crate::{
+    enrich::Ticket,
+    hook::use_intersection::{use_intersection, IntersectionOptions},
+};
+
+/// Queue a card's requests behind its visibility.
+///
+/// Returns an id that must be given to the card's root element, along with the
+/// ticket its requests are made through.
+pub fn use_enrichment(cx: &ScopeState) -> (&str, &Ticket) {
+    let (id, intersection) = use_intersection(
+        cx,
+        IntersectionOptions {
+            // Start a little before the card scrolls into view
+            root_margin: "200px 0px",
+            ..Default::default()
+        },
+    );
+    let ticket: &Ticket = cx.use_hook(|_| Ticket::offscreen());
+
+    ticket.set_visible(intersection.visible);
+
+    (id, ticket)
+}
@@ -0,0 +1,322 @@
+use std::iter;
+
+use dioxus::{core::to_owned, prelude::*};
+use log::warn;
+use serde::Deserialize;
+use time::OffsetDateTime;
+
+use crate::{
+    gh::{self, GithubApiError},
+    hook::use_repos::RefetchFn,
+};
+
+/// Events per page, the most github allows
+const PER_PAGE: usize = 100;
+/// Github only keeps the last 300 public events of an account
+const MAX_PAGES: u32 = 3;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Event {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub actor: Actor,
+    pub repo: EventRepo,
+    pub payload: serde_json::Value,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Actor {
+    pub login: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct EventRepo {
+    /// Full name of the repository, ie `owner/name`
+    pub name: String,
+}
+
+/// The events fetched so far for one account
+struct Account {
+    user: &'static str,
+    /// Most recent first
+    events: Vec<Event>,
+    /// The next page to fetch, `None` once there are no more
+    next_page: Option<u32>,
+}
+
+pub struct Events<'state> {
+    /// Events of every account, most recent first
+    pub events: Vec<Event>,
+    /// The result of fetching the latest pages, `Ok(true)` if there are more pages.
+    /// The accounts that did load are still in `events` if another failed.
+    pub state: Option<&'state Result<bool, GithubApiError>>,
+    /// Fetch the next page of events
+    pub next_page: Box<dyn Fn() + 'state>,
+    /// Retry fetching the latest pages of the accounts that failed
+    pub refetch: RefetchFn<'state>,
+}
+
+/// Fetch the public events of every account, merged into one timeline one page at a time
+pub fn use_events<'state>(
+    cx: &'state ScopeState,
+    users: &'static [&'static str],
+) -> Events<'state> {
+    let round = use_state(cx, || 0_u32);
+    let accounts = use_ref(cx, || {
+        users
+            .iter()
+            .map(|&user| Account {
+                user,
+                events: Vec::new(),
+                next_page: Some(1),
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let future = use_future(cx, (*round.get(),), move |_| {
+        to_owned![accounts];
+
+        async move {
+            // Accounts keep their own place, so a retry only refetches those that failed
+            let pending = accounts
+                .read()
+                .iter()
+                .filter_map(|account| account.next_page.map(|page| (account.user, page)))
+                .collect::<Vec<_>>();
+
+            let pages = futures::future::join_all(
+                pending.iter().map(|&(user, page)| fetch_events(user, page)),
+            )
+            .await;
+
+            let mut failure = None;
+
+            accounts.with_mut(|accounts| {
+                for ((user, page), fetched) in iter::zip(pending, pages) {
+                    let account = accounts
+                        .iter_mut()
+                        .find(|account| account.user == user)
+                        .expect("events fetched for an unknown account");
+
+                    match fetched {
+                        Ok(events) => {
+                            account.next_page =
+                                (page < MAX_PAGES && events.len() == PER_PAGE).then(|| page + 1);
+                            account.events.extend(events);
+                        }
+                        Err(error) => {
+                            warn!("Failed to fetch page {page} of the events of {user}");
+
+                            failure.get_or_insert(error);
+                        }
+                    }
+                }
+            });
+
+            match failure {
+                Some(error) => Err(error),
+                None => Ok(accounts
+                    .read()
+                    .iter()
+                    .any(|account| account.next_page.is_some())),
+            }
+        }
+    });
+
+    Events {
+        events: merge(&accounts.read()),
+        state: future.value(),
+        next_page: Box::new(move || {
+            if let Some(Ok(true)) = future.value() {
+                round.modify(|round| round + 1);
+            }
+        }),
+        refetch: super::refetch(future),
+    }
+}
+
+/// Merge the events of every account into one timeline, most recent first.
+///
+/// An account with more pages may have events older than those fetched so far
+/// but newer than those of quieter accounts, so nothing older than its oldest
+/// fetched event is shown until its next page is in.
+fn merge(accounts: &[Account]) -> Vec<Event> {
+    let cutoff = accounts
+        .iter()
+        .filter(|account| account.next_page.is_some())
+        .filter_map(|account| account.events.iter().map(|event| event.created_at).min())
+        .max();
+
+    let mut events = accounts
+        .iter()
+        .flat_map(|account| &account.events)
+        .filter(|event| cutoff.map_or(true, |cutoff| event.created_at >= cutoff))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    events.sort_by(|a, b| {
+        b.created_at
+            .cmp(&a.created_at)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    events.dedup_by(|a, b| a.id == b.id);
+
+    events
+}
+
+async fn fetch_events(user: &str, page: u32) -> Result<Vec<Event>, GithubApiError> {
+    Ok(gh::fetch(&format!(
+        "https://api.github.com/users/{user}/events/public?per_page={PER_PAGE}&page={page}"
+    ))
+    .await?
+    .json::<Vec<Event>>()
+    .await
+    .expect("received unexpected json content"))
+}
+
+/// A readable summary of one or more events
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub actor: String,
+    pub description: String,
+    pub url: String,
+    pub created_at: OffsetDateTime,
+}
+
+/// Summarize the events into readable entries, combining consecutive pushes to
+/// the same repository and skipping events that are not interesting
+pub fn entries(events: &[Event]) -> Vec<Entry> {
+    let mut entries = Vec::<(Option<(String, String)>, u64, Entry)>::new();
+
+    for event in events {
+        let payload = &event.payload;
+        let repo = &event.repo.name;
+        let repo_url = format!("https://github.com/{repo}");
+        let str_field = |pointer: &str| payload.pointer(pointer).and_then(|value| value.as_str());
+        let number = payload
+            .pointer("/number")
+            .or_else(|| payload.pointer("/issue/number"))
+            .and_then(|number| number.as_u64())
+            .unwrap_or_default();
+
+        let (description, url) = match event.kind.as_str() {
+            "PushEvent" => {
+                let commits = payload
+                    .pointer("/size")
+                    .and_then(|size| size.as_u64())
+                    .unwrap_or(1);
+                let branch = str_field("/ref")
+                    .map(|git_ref| git_ref.trim_start_matches("refs/heads/"))
+                    .unwrap_or_default()
+                    .to_string();
+                let key = Some((event.actor.login.clone(), format!("{repo}#{branch}")));
+
+                // Fold into the previous entry if it was a push to the same branch
+                if let Some((previous_key, previous_commits, previous)) = entries.last_mut() {
+                    if *previous_key == key {
+                        *previous_commits += commits;
+                        previous.description = push_description(*previous_commits, repo, &branch);
+
+                        continue;
+                    }
+                }
+
+                entries.push((
+                    key,
+                    commits,
+                    Entry {
+                        actor: event.actor.login.clone(),
+                        description: push_description(commits, repo, &branch),
+                        url: format!("{repo_url}/commits/{branch}"),
+                        created_at: event.created_at,
+                    },
+                ));
+
+                continue;
+            }
+            "PullRequestEvent" => {
+                let action = match (
+                    str_field("/action"),
+                    payload
+                        .pointer("/pull_request/merged")
+                        .and_then(|merged| merged.as_bool()),
+                ) {
+                    (Some("closed"), Some(true)) => "merged",
+                    (Some(action), _) => action,
+                    (None, _) => continue,
+                };
+                let title = str_field("/pull_request/title").unwrap_or_default();
+
+                (
+                    format!("{action} pull request #{number} in {repo}: {title}"),
+                    str_field("/pull_request/html_url")
+                        .map_or_else(|| repo_url.clone(), String::from),
+                )
+            }
+            "IssuesEvent" => {
+                let action = match str_field("/action") {
+                    Some(action) => action,
+                    None => continue,
+                };
+                let title = str_field("/issue/title").unwrap_or_default();
+
+                (
+                    format!("{action} issue #{number} in {repo}: {title}"),
+                    str_field("/issue/html_url").map_or_else(|| repo_url.clone(), String::from),
+                )
+            }
+            "ReleaseEvent" => {
+                let tag = str_field("/release/tag_name").unwrap_or_default();
+
+                (
+                    format!("released {tag} of {repo}"),
+                    str_field("/release/html_url").map_or_else(|| repo_url.clone(), String::from),
+                )
+            }
+            "WatchEvent" => (format!("starred {repo}"), repo_url.clone()),
+            "CreateEvent" => match (str_field("/ref_type"), str_field("/ref")) {
+                (Some("repository"), _) => (format!("created repository {repo}"), repo_url.clone()),
+                (Some(ref_type), Some(git_ref)) => (
+                    format!("created {ref_type} {git_ref} in {repo}"),
+                    format!("{repo_url}/tree/{git_ref}"),
+                ),
+                _ => continue,
+            },
+            "ForkEvent" => {
+                let fork = str_field("/forkee/full_name").unwrap_or_default();
+
+                (
+                    format!("forked {repo} to {fork}"),
+                    str_field("/forkee/html_url").map_or_else(|| repo_url.clone(), String::from),
+                )
+            }
+            _ => continue,
+        };
+
+        entries.push((
+            None,
+            0,
+            Entry {
+                actor: event.actor.login.clone(),
+                description,
+                url,
+                created_at: event.created_at,
+            },
+        ));
+    }
+
+    entries.into_iter().map(|(_, _, entry)| entry).collect()
+}
+
+fn push_description(commits: u64, repo: &str, branch: &str) -> String {
+    let plural = if commits == 1 { "" } else { "s" };
+
+    if branch.is_empty() {
+        format!("pushed {commits} commit{plural} to {repo}")
+    } else {
+        format!("pushed {commits} commit{plural} to {repo} ({branch})")
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+use serde::Deserialize;
+use time::OffsetDateTime;
+
+use crate::{
+    gh::{self, GithubApiError},
+    hook::use_repos::RefetchFn,
+};
+
+#[derive(Debug)]
+pub struct PullRequest {
+    pub number: u64,
+    pub title: String,
+    pub html_url: String,
+    pub author: String,
+    pub merged_at: Option<OffsetDateTime>,
+}
+
+#[derive(Debug)]
+pub struct ExternalRepo {
+    pub full_name: String,
+    pub html_url: String,
+    /// Merged pull requests, most recently merged first
+    pub pull_requests: Vec<PullRequest>,
+}
+
+/// Fetch the pull requests merged into repositories not owned by any of the
+/// accounts, grouped by repository with the most contributed to first
+pub fn use_external_contributions<'state>(
+    cx: &'state ScopeState,
+    users: &'static [&'static str],
+) -> Option<(
+    &'state Result<Vec<ExternalRepo>, GithubApiError>,
+    RefetchFn<'state>,
+)> {
+    let future = use_future(cx, (), move |()| fetch_external_contributions(users));
+
+    super::with_refetch(future)
+}
+
+async fn fetch_external_contributions(
+    users: &'static [&'static str],
+) -> Result<Vec<ExternalRepo>, GithubApiError> {
+    #[derive(Deserialize)]
+    struct SearchResults {
+        items: Vec<Issue>,
+    }
+
+    #[derive(Deserialize)]
+    struct Issue {
+        number: u64,
+        title: String,
+        html_url: String,
+        repository_url: String,
+        user: User,
+        pull_request: Option<PullRequestLinks>,
+    }
+
+    #[derive(Deserialize)]
+    struct User {
+        login: String,
+    }
+
+    #[derive(Deserialize)]
+    struct PullRequestLinks {
+        #[serde(default, with = "time::serde::rfc3339::option")]
+        merged_at: Option<OffsetDateTime>,
+    }
+
+    // Exclude repositories owned by any of the accounts, not just the author
+    let excluded = users
+        .iter()
+        .map(|user| format!("+-user:{user}"))
+        .collect::<String>();
+
+    let searches = futures::future::try_join_all(users.iter().map(|user| {
+        let mut url = format!(
+            "https://api.github.com/search/issues?q=is:pr+is:merged+author:{user}{excluded}&sort=updated&per_page=100"
+        );
+
+        async move {
+            let mut items = Vec::new();
+
+            // Search results are wrapped in an object, so cannot use `gh::fetch_all_pages`
+            loop {
+                let response = gh::fetch(&url).await?;
+
+                items.extend(
+                    response
+                        .json::<SearchResults>()
+                        .await
+                        .expect("received unexpected json content")
+                        .items,
+                );
+
+                match gh::next_page(&response) {
+                    Some(next) => url = next,
+                    None => break,
+                }
+            }
+
+            Ok::<_, GithubApiError>(items)
+        }
+    }))
+    .await?;
+
+    let mut repos = HashMap::<String, Vec<PullRequest>>::new();
+
+    for issue in searches.into_iter().flatten() {
+        repos
+            .entry(issue.repository_url)
+            .or_default()
+            .push(PullRequest {
+                number: issue.number,
+                title: issue.title,
+                html_url: issue.html_url,
+                author: issue.user.login,
+                merged_at: issue.pull_request.and_then(|links| links.merged_at),
+            });
+    }
+
+    let mut repos = repos
+        .into_iter()
+        .map(|(repository_url, mut pull_requests)| {
+            // Search results only link to the repository in the api
+            let full_name = repository_url
+                .trim_start_matches("https://api.github.com/repos/")
+                .to_string();
+
+            pull_requests.sort_by(|a, b| b.merged_at.cmp(&a.merged_at));
+
+            ExternalRepo {
+                html_url: format!("https://github.com/{full_name}"),
+                full_name,
+                pull_requests,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    repos.sort_by(|a, b| {
+        b.pull_requests
+            .len()
+            .cmp(&a.pull_requests.len())
+            .then_with(|| a.full_name.cmp(&b.full_name))
+    });
+
+    Ok(repos)
+}
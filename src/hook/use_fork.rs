@@ -0,0 +1,105 @@
+use dioxus::prelude::*;
+use serde::Deserialize;
+
+use crate::{
+    config,
+    enrich::{self, Cache, Ticket},
+    gh::{self, GithubApiError},
+    hook::use_repos::Repo,
+};
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ParentRepo {
+    pub full_name: String,
+    pub html_url: String,
+    pub default_branch: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Fork {
+    pub parent: ParentRepo,
+    /// Commits on the fork's default branch that are not upstream
+    pub ahead_by: Option<u32>,
+    /// Commits upstream that are not on the fork's default branch
+    pub behind_by: Option<u32>,
+}
+
+thread_local! {
+    static CACHE: Cache<Fork> = Cache::default();
+}
+
+/// Fetch the upstream of a forked repository, along with how far it has diverged.
+///
+/// Resolves to `None` for repositories that are not forks, or if the upstream
+/// could not be fetched.
+pub fn use_fork<'state>(
+    cx: &'state ScopeState,
+    repo: &Repo,
+    ticket: &Ticket,
+) -> Option<&'state Option<Fork>> {
+    let is_fork = repo.fork;
+    let full_name = repo.full_name.clone();
+    let owner = repo.owner.login.clone();
+    let default_branch = repo.default_branch.clone();
+    let ticket = ticket.clone();
+
+    let future = use_future(cx, (), move |()| async move {
+        if !(config::FETCH_FORKS && is_fork) {
+            return None;
+        }
+
+        let request = ticket.run(fetch_fork(&full_name, &owner, &default_branch));
+
+        enrich::cached(&CACHE, &full_name, request).await.ok()
+    });
+
+    future.value()
+}
+
+async fn fetch_fork(
+    full_name: &str,
+    owner: &str,
+    default_branch: &str,
+) -> Result<Fork, GithubApiError> {
+    #[derive(Deserialize)]
+    struct RepoWithParent {
+        parent: ParentRepo,
+    }
+
+    #[derive(Deserialize)]
+    struct Comparison {
+        ahead_by: u32,
+        behind_by: u32,
+    }
+
+    // The parent is only included when fetching the repository on its own
+    let parent = gh::fetch(&format!("https://api.github.com/repos/{full_name}"))
+        .await?
+        .json::<RepoWithParent>()
+        .await
+        .expect("received unexpected json content")
+        .parent;
+
+    let comparison = match gh::fetch(&format!(
+        "https://api.github.com/repos/{}/compare/{}...{owner}:{default_branch}",
+        parent.full_name, parent.default_branch
+    ))
+    .await
+    {
+        Ok(response) => Some(
+            response
+                .json::<Comparison>()
+                .await
+                .expect("received unexpected json content"),
+        ),
+        // The histories may have nothing in common anymore
+        Err(GithubApiError::NotFound { .. }) => None,
+        Err(error) => return Err(error),
+    };
+
+    Ok(Fork {
+        parent,
+        ahead_by: comparison.as_ref().map(|comparison| comparison.ahead_by),
+        behind_by: comparison.as_ref().map(|comparison| comparison.behind_by),
+    })
+}
@@ -0,0 +1,66 @@
+use std::collections::BTreeMap;
+
+use dioxus::prelude::*;
+use serde::Deserialize;
+
+use crate::{
+    gh::{self, GithubApiError},
+    hook::use_repos::{Gist, RefetchFn},
+};
+
+/// How many lines of the file to show in the preview
+const PREVIEW_LINES: usize = 20;
+
+/// Fetch the start of the first file in a gist, resolving to `Ok(None)` if the gist is empty
+pub fn use_gist_preview<'state>(
+    cx: &'state ScopeState,
+    gist: &Gist,
+) -> Option<(
+    &'state Result<Option<String>, GithubApiError>,
+    RefetchFn<'state>,
+)> {
+    let id = gist.id.clone();
+
+    let future = use_future(cx, (), move |()| async move { fetch_preview(&id).await });
+
+    super::with_refetch(future)
+}
+
+async fn fetch_preview(id: &str) -> Result<Option<String>, GithubApiError> {
+    #[derive(Deserialize)]
+    struct Gist {
+        files: BTreeMap<String, File>,
+    }
+
+    #[derive(Deserialize)]
+    struct File {
+        content: Option<String>,
+    }
+
+    // The file contents are only included when fetching a gist on its own
+    let gist = gh::fetch(&format!("https://api.github.com/gists/{id}"))
+        .await?
+        .json::<Gist>()
+        .await
+        .expect("received unexpected json content");
+
+    Ok(gist
+        .files
+        .into_values()
+        .next()
+        .and_then(|file| file.content)
+        .map(|content| {
+            let mut lines = content.lines();
+            let mut preview = lines
+                .by_ref()
+                .take(PREVIEW_LINES)
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            if lines.next().is_some() {
+                preview.push_str("\n…");
+            }
+
+            preview
+        }))
+}
@@ -0,0 +1,167 @@
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use js_sys::Array;
+use log::warn;
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use web_sys::{window, IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit};
+
+/// How long to wait between looks for the observed element, in milliseconds
+const RETRY_DELAY: u32 = 16;
+/// How many times to look for the observed element, about five seconds' worth
+const ATTEMPTS: u32 = 300;
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IntersectionOptions {
+    /// Ratios of visibility, from 0 to 1, at which to update
+    pub thresholds: &'static [f64],
+    /// Grows or shrinks the viewport, in css margin syntax
+    pub root_margin: &'static str,
+}
+
+impl Default for IntersectionOptions {
+    fn default() -> Self {
+        Self {
+            thresholds: &[0.0],
+            root_margin: "0px",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Intersection {
+    pub visible: bool,
+    /// How much of the element is visible, from 0 to 1
+    pub ratio: f64,
+}
+
+/// Disconnects the observer when the component's hooks are dropped
+struct Observer {
+    observer: IntersectionObserver,
+    _callback: Closure<dyn FnMut(Array)>,
+}
+
+impl Drop for Observer {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
+
+/// Track how much of an element is visible in the viewport.
+///
+/// Returns an id that must be given to the observed element, along with its
+/// latest intersection. Elements count as hidden until first observed.
+pub fn use_intersection<'state>(
+    cx: &'state ScopeState,
+    options: IntersectionOptions,
+) -> (&'state str, Intersection) {
+    let id = cx.use_hook(|_| format!("intersection-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed)));
+    let intersection = use_state(cx, Intersection::default);
+    let observer = cx.use_hook(|_| Rc::new(RefCell::new(None::<Observer>)));
+
+    use_future(cx, (), {
+        let id = id.clone();
+        let intersection = intersection.to_owned();
+        // Only the component keeps the observer alive, so it going away means unmounted
+        let observer = Rc::downgrade(observer);
+
+        move |()| async move {
+            // The element only exists once the first render has been applied, which
+            // may take a while on a busy page
+            let document = window()
+                .and_then(|window| window.document())
+                .expect("document should always exist");
+
+            let mut element = None;
+            for _ in 0..ATTEMPTS {
+                TimeoutFuture::new(RETRY_DELAY).await;
+
+                if observer.strong_count() == 0 {
+                    return;
+                }
+
+                element = document.get_element_by_id(&id);
+                if element.is_some() {
+                    break;
+                }
+            }
+
+            let element = match element {
+                Some(element) => element,
+                None => {
+                    warn!("element #{id} was never rendered, treating it as visible");
+
+                    // Better to treat the element as visible than to never show it
+                    intersection.set(Intersection {
+                        visible: true,
+                        ratio: 1.0,
+                    });
+
+                    return;
+                }
+            };
+
+            let callback = Closure::wrap(Box::new({
+                let intersection = intersection.clone();
+
+                move |entries: Array| {
+                    if let Some(entry) = entries.iter().last() {
+                        let entry = entry.unchecked_into::<IntersectionObserverEntry>();
+
+                        intersection.set(Intersection {
+                            visible: entry.is_intersecting(),
+                            ratio: entry.intersection_ratio(),
+                        });
+                    }
+                }
+            }) as Box<dyn FnMut(Array)>);
+
+            let thresholds = options
+                .thresholds
+                .iter()
+                .map(|&threshold| JsValue::from(threshold))
+                .collect::<Array>();
+
+            let created = IntersectionObserver::new_with_options(
+                callback.as_ref().unchecked_ref(),
+                IntersectionObserverInit::new()
+                    .threshold(&thresholds)
+                    .root_margin(options.root_margin),
+            );
+
+            match created {
+                Ok(created) => {
+                    created.observe(&element);
+
+                    let created = Observer {
+                        observer: created,
+                        _callback: callback,
+                    };
+
+                    // Dropped, and so disconnected, if the component has gone away
+                    if let Some(observer) = observer.upgrade() {
+                        *observer.borrow_mut() = Some(created);
+                    }
+                }
+                Err(error) => {
+                    warn!("failed to create intersection observer: {error:?}");
+
+                    // Better to treat the element as visible than to never show it
+                    intersection.set(Intersection {
+                        visible: true,
+                        ratio: 1.0,
+                    });
+                }
+            }
+        }
+    });
+
+    (id.as_str(), *intersection.get())
+}
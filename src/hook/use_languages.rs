@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+
+use crate::{
+    enrich::Ticket,
+    gh::{self, GithubApiError},
+    hook::use_repos::{RefetchFn, Repo},
+};
+
+/// Fetch the bytes of code written in each language of a repository, largest first
+pub fn use_languages<'state>(
+    cx: &'state ScopeState,
+    repo: &Repo,
+    ticket: &Ticket,
+) -> Option<(
+    &'state Result<Vec<(String, u64)>, GithubApiError>,
+    RefetchFn<'state>,
+)> {
+    let full_name = repo.full_name.clone();
+    let ticket = ticket.clone();
+
+    let future = use_future(cx, (), move |()| async move {
+        ticket.run(fetch_languages(&full_name)).await
+    });
+
+    super::with_refetch(future)
+}
+
+async fn fetch_languages(full_name: &str) -> Result<Vec<(String, u64)>, GithubApiError> {
+    let languages = gh::fetch(&format!(
+        "https://api.github.com/repos/{full_name}/languages"
+    ))
+    .await?
+    .json::<HashMap<String, u64>>()
+    .await
+    .expect("received unexpected json content");
+
+    let mut languages = languages.into_iter().collect::<Vec<_>>();
+    languages.sort_by(|(a_name, a_bytes), (b_name, b_bytes)| {
+        b_bytes.cmp(a_bytes).then_with(|| a_name.cmp(b_name))
+    });
+
+    Ok(languages)
+}
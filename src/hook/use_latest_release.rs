@@ -0,0 +1,78 @@
+use dioxus::prelude::*;
+use serde::Deserialize;
+
+use crate::{
+    config,
+    enrich::{self, Cache, Ticket},
+    gh::{self, GithubApiError},
+    hook::use_repos::{Release, Repo},
+};
+
+thread_local! {
+    static CACHE: Cache<Option<Release>> = Cache::default();
+}
+
+/// Fetch the latest release of a repository, or its latest tag if it has no releases.
+///
+/// Resolves to `None` if it has neither or they could not be fetched.
+pub fn use_latest_release<'state>(
+    cx: &'state ScopeState,
+    repo: &Repo,
+    ticket: &Ticket,
+) -> Option<&'state Option<Release>> {
+    let full_name = repo.full_name.clone();
+    let ticket = ticket.clone();
+
+    let future = use_future(cx, (), move |()| async move {
+        if !config::FETCH_RELEASES {
+            return None;
+        }
+
+        let request = ticket.run(fetch_latest_release(&full_name));
+
+        enrich::cached_or_default(&CACHE, &full_name, request).await
+    });
+
+    future.value()
+}
+
+async fn fetch_latest_release(full_name: &str) -> Result<Option<Release>, GithubApiError> {
+    match gh::fetch(&format!(
+        "https://api.github.com/repos/{full_name}/releases/latest"
+    ))
+    .await
+    {
+        Ok(response) => {
+            return Ok(Some(
+                response
+                    .json::<Release>()
+                    .await
+                    .expect("received unexpected json content"),
+            ))
+        }
+        Err(GithubApiError::NotFound { .. }) => {}
+        Err(error) => return Err(error),
+    }
+
+    #[derive(Deserialize)]
+    struct Tag {
+        name: String,
+    }
+
+    let tags = gh::fetch(&format!(
+        "https://api.github.com/repos/{full_name}/tags?per_page=1"
+    ))
+    .await?
+    .json::<Vec<Tag>>()
+    .await
+    .expect("received unexpected json content");
+
+    Ok(tags.into_iter().next().map(|tag| Release {
+        html_url: format!("https://github.com/{full_name}/releases/tag/{}", tag.name),
+        tag_name: tag.name,
+        name: None,
+        prerelease: false,
+        published_at: None,
+        body: None,
+    }))
+}
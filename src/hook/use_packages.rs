@@ -0,0 +1,31 @@
+use dioxus::prelude::*;
+
+use crate::{
+    enrich::{self, Cache, Ticket},
+    hook::use_repos::Repo,
+    registry::{self, Package},
+};
+
+thread_local! {
+    static CACHE: Cache<Vec<Package>> = Cache::default();
+}
+
+/// Detect the packages published from a repository.
+///
+/// Resolves to an empty list if detection failed.
+pub fn use_packages<'state>(
+    cx: &'state ScopeState,
+    repo: &Repo,
+    ticket: &Ticket,
+) -> Option<&'state Vec<Package>> {
+    let full_name = repo.full_name.clone();
+    let ticket = ticket.clone();
+
+    let future = use_future(cx, (), move |()| async move {
+        let request = ticket.run(registry::detect_packages(&full_name));
+
+        enrich::cached_or_default(&CACHE, &full_name, request).await
+    });
+
+    future.value()
+}
@@ -0,0 +1,64 @@
+use dioxus::prelude::*;
+use serde::Deserialize;
+use time::OffsetDateTime;
+
+use crate::gh::{self, GithubApiError};
+
+#[derive(Deserialize, Debug)]
+pub struct Profile {
+    pub login: String,
+    pub name: Option<String>,
+    pub avatar_url: String,
+    pub html_url: String,
+    pub bio: Option<String>,
+    pub location: Option<String>,
+    pub company: Option<String>,
+    pub blog: Option<String>,
+    pub email: Option<String>,
+    pub followers: u32,
+    pub following: u32,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(skip)]
+    pub social_accounts: Vec<SocialAccount>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SocialAccount {
+    pub provider: String,
+    pub url: String,
+}
+
+/// Fetch the public profile of an account along with its linked social accounts
+pub fn use_profile<'state>(
+    cx: &'state ScopeState,
+    user: &'static str,
+) -> Option<&'state Result<Profile, GithubApiError>> {
+    let future = use_future(cx, (), move |()| fetch_profile(user));
+
+    future.value()
+}
+
+async fn fetch_profile(user: &str) -> Result<Profile, GithubApiError> {
+    let (profile, social_accounts) = futures::join!(
+        gh::fetch(&format!("https://api.github.com/users/{user}")),
+        gh::fetch(&format!(
+            "https://api.github.com/users/{user}/social_accounts"
+        ))
+    );
+
+    let mut profile = profile?
+        .json::<Profile>()
+        .await
+        .expect("received unexpected json content");
+
+    // The social accounts are an extra, so the profile is still worth showing without them
+    if let Ok(social_accounts) = social_accounts {
+        profile.social_accounts = social_accounts
+            .json()
+            .await
+            .expect("received unexpected json content");
+    }
+
+    Ok(profile)
+}
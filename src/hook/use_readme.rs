@@ -0,0 +1,73 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use dioxus::prelude::*;
+use log::{debug, warn};
+
+use crate::{
+    gh::{self, GithubApiError},
+    hook::use_repos::{RefetchFn, Repo},
+    markdown::{self, RepoLinks},
+};
+
+thread_local! {
+    /// Rendered readmes keyed by the repository's full name, `None` if it has no readme
+    static CACHE: RefCell<HashMap<String, Option<Rc<str>>>> = RefCell::new(HashMap::new());
+}
+
+/// Fetch and render the readme of a repository, resolving to `Ok(None)` if it has none
+pub fn use_readme<'state>(
+    cx: &'state ScopeState,
+    repo: &Repo,
+) -> Option<(
+    &'state Result<Option<Rc<str>>, GithubApiError>,
+    RefetchFn<'state>,
+)> {
+    let full_name = repo.full_name.clone();
+    let default_branch = repo.default_branch.clone();
+
+    let future = use_future(cx, (), move |()| async move {
+        if let Some(cached) = CACHE.with(|cache| cache.borrow().get(&full_name).cloned()) {
+            debug!("Using cached readme for {full_name}");
+
+            return Ok(cached);
+        }
+
+        let readme = fetch_readme(&full_name, &default_branch).await?;
+
+        CACHE.with(|cache| cache.borrow_mut().insert(full_name.clone(), readme.clone()));
+
+        Ok(readme)
+    });
+
+    super::with_refetch(future)
+}
+
+async fn fetch_readme(
+    full_name: &str,
+    default_branch: &str,
+) -> Result<Option<Rc<str>>, GithubApiError> {
+    let response =
+        match gh::fetch(&format!("https://api.github.com/repos/{full_name}/readme")).await {
+            Ok(response) => response,
+            Err(GithubApiError::NotFound { .. }) => return Ok(None),
+            Err(error) => return Err(error),
+        };
+
+    let content = response
+        .json::<gh::Content>()
+        .await
+        .expect("received unexpected json content");
+
+    // Readmes over a megabyte are not included, and come back with an encoding of `none`
+    let readme = match content.try_decode() {
+        Some(readme) => String::from_utf8_lossy(&readme).into_owned(),
+        None => {
+            warn!("The readme of {full_name} is too large to show");
+
+            return Ok(None);
+        }
+    };
+    let links = RepoLinks::new(full_name, default_branch, &content.path);
+
+    Ok(Some(markdown::render(&readme, Some(&links)).into()))
+}
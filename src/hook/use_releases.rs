@@ -0,0 +1,39 @@
+use dioxus::prelude::*;
+
+use crate::{
+    enrich::Ticket,
+    gh::{self, GithubApiError},
+    hook::use_repos::{RefetchFn, Release, Repo},
+};
+
+/// How many of the most recent releases to show on a repository's page
+const RELEASES: u32 = 10;
+
+/// Fetch the most recent releases of a repository, newest first
+pub fn use_releases<'state>(
+    cx: &'state ScopeState,
+    repo: &Repo,
+    ticket: &Ticket,
+) -> Option<(
+    &'state Result<Vec<Release>, GithubApiError>,
+    RefetchFn<'state>,
+)> {
+    let full_name = repo.full_name.clone();
+    let ticket = ticket.clone();
+
+    let future = use_future(cx, (), move |()| async move {
+        ticket.run(fetch_releases(&full_name)).await
+    });
+
+    super::with_refetch(future)
+}
+
+async fn fetch_releases(full_name: &str) -> Result<Vec<Release>, GithubApiError> {
+    Ok(gh::fetch(&format!(
+        "https://api.github.com/repos/{full_name}/releases?per_page={RELEASES}"
+    ))
+    .await?
+    .json::<Vec<Release>>()
+    .await
+    .expect("received unexpected json content"))
+}
@@ -0,0 +1,47 @@
+use dioxus::prelude::*;
+use serde::Deserialize;
+
+use crate::{
+    enrich::{self, Cache, Ticket},
+    gh::{self, GithubApiError},
+};
+
+thread_local! {
+    static CACHE: Cache<Option<String>> = Cache::default();
+}
+
+/// Fetch the primary language of a repository known only by its full name.
+///
+/// Resolves to `None` if the repository has no language or could not be fetched.
+pub fn use_repo_language<'state>(
+    cx: &'state ScopeState,
+    full_name: &str,
+    ticket: &Ticket,
+) -> Option<&'state Option<String>> {
+    let full_name = full_name.to_string();
+    let ticket = ticket.clone();
+
+    let future = use_future(cx, (), move |()| async move {
+        let request = ticket.run(fetch_language(&full_name));
+
+        enrich::cached_or_default(&CACHE, &full_name, request).await
+    });
+
+    future.value()
+}
+
+async fn fetch_language(full_name: &str) -> Result<Option<String>, GithubApiError> {
+    #[derive(Deserialize)]
+    struct Repository {
+        language: Option<String>,
+    }
+
+    Ok(
+        gh::fetch(&format!("https://api.github.com/repos/{full_name}"))
+            .await?
+            .json::<Repository>()
+            .await
+            .expect("received unexpected json content")
+            .language,
+    )
+}
@@ -1,22 +1,26 @@
-use std::{collections::HashMap, iter};
+use std::{collections::BTreeMap, iter};
 
-use css_colors::{rgb, RGB};
+use css_colors::RGB;
 use dioxus::prelude::*;
-use log::{debug, trace};
-use once_cell::sync::Lazy;
-use regex::Regex;
+use log::warn;
 use serde::Deserialize;
 use time::OffsetDateTime;
 
-use crate::gh::{self, GithubApiError};
+use crate::{
+    colors, config,
+    gh::{self, GithubApiError},
+};
 
 #[derive(Deserialize, Debug)]
 pub struct Repo {
     pub name: String,
+    pub full_name: String,
     pub node_id: String,
+    pub default_branch: String,
     pub html_url: String,
     pub description: Option<String>,
     pub language: Option<String>,
+    pub fork: bool,
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
     #[serde(with = "time::serde::rfc3339")]
@@ -32,156 +36,200 @@ pub struct Owner {
     pub html_url: String,
 }
 
+#[derive(Deserialize, Debug, Clone)]
+pub struct Release {
+    pub tag_name: String,
+    pub name: Option<String>,
+    pub html_url: String,
+    #[serde(default)]
+    pub prerelease: bool,
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub published_at: Option<OffsetDateTime>,
+    #[serde(default)]
+    pub body: Option<String>,
+}
+
+/// A repository as listed on the page
 #[derive(Debug)]
-pub struct RepoAndColor {
+pub struct ListedRepo {
     pub repo: Repo,
-    pub color: Option<RGB>,
+}
+
+impl ListedRepo {
+    /// The color of the repository's language, looked up on every render since
+    /// the language colors may be refreshed after the repositories are fetched
+    pub fn color(&self) -> RGB {
+        colors::color_for(self.repo.language.as_deref())
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Gist {
+    pub id: String,
+    pub node_id: String,
+    pub html_url: String,
+    pub description: Option<String>,
+    /// Files in the gist, keyed and ordered by file name
+    pub files: BTreeMap<String, GistFile>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GistFile {
+    pub filename: String,
+    pub language: Option<String>,
+    pub size: u64,
+}
+
+#[derive(Debug)]
+pub struct UserRepos {
+    /// The account itself, which is known even if it has no public repositories
+    pub account: Owner,
+    pub repos: Vec<ListedRepo>,
+    pub gists: Vec<Gist>,
+    /// Node ids of the pinned repositories, in the order they are pinned
+    pub pinned: Vec<String>,
+}
+
+impl UserRepos {
+    pub fn pinned(&self) -> impl Iterator<Item = &ListedRepo> {
+        self.pinned
+            .iter()
+            .filter_map(|node_id| self.repos.iter().find(|repo| &repo.repo.node_id == node_id))
+    }
 }
 
 pub type RefetchFn<'f> = Box<dyn Fn() + 'f>;
 
-type Repos = HashMap<&'static str, Vec<RepoAndColor>>;
+type Repos = Vec<(&'static str, UserRepos)>;
 
 pub fn use_repos<'state>(
     cx: &'state ScopeState,
     users: Vec<&'static str>,
 ) -> Option<(&'state Result<Repos, GithubApiError>, RefetchFn<'state>)> {
     let future = use_future(cx, (), move |()| async move {
-        let result = futures::try_join!(
-            fetch_colors(),
-            futures::future::try_join_all(users.iter().map(|user| fetch_all_user_repos(user)))
-        );
-
-        result.map(|(colors, repos)| {
-            iter::zip(
-                users.iter().copied(),
-                repos.into_iter().map(|repos| {
-                    repos
-                        .into_iter()
-                        .map(|repo| RepoAndColor {
-                            color: repo
-                                .language
-                                .as_ref()
-                                .and_then(|language| colors.get(language).copied()),
-                            repo,
-                        })
-                        .collect()
-                }),
-            )
-            .collect()
-        })
-    });
+        let result = futures::future::try_join_all(users.iter().map(|user| async move {
+            let (account, repos, gists, pinned) = futures::join!(
+                fetch_account(user),
+                fetch_all_user_repos(user),
+                fetch_all_user_gists(user),
+                fetch_pinned(user)
+            );
+
+            let gists = gists.unwrap_or_else(|_| {
+                warn!("Failed to fetch the gists of {user}");
+
+                Vec::new()
+            });
 
-    future.value().map(|res| {
-        (
-            res,
-            Box::new(|| {
-                future.clear();
-                future.restart();
-            }) as Box<_>,
-        )
-    })
-}
+            Ok::<_, GithubApiError>((account?, repos?, gists, pinned?))
+        }))
+        .await;
 
-async fn fetch_colors() -> Result<HashMap<String, RGB>, GithubApiError> {
-    let response =
-        gh::fetch("https://api.github.com/repos/ozh/github-colors/contents/colors.json").await?;
+        result.map(|repos| {
+            iter::zip(users.iter().copied(), repos)
+                .map(|(user, (account, repos, gists, pinned))| {
+                    let pinned = pinned.unwrap_or_else(|| {
+                        let fallback = config::pinned_fallback(user);
 
-    let json = response
-        .json::<serde_json::Value>()
-        .await
-        .expect("encountered non-json response body");
+                        // In the configured order, which stands in for the pinned order
+                        fallback
+                            .iter()
+                            .filter_map(|name| repos.iter().find(|repo| repo.name == *name))
+                            .map(|repo| repo.node_id.clone())
+                            .collect()
+                    });
 
-    assert_eq!(json["encoding"], "base64", "non base64 encoding used");
+                    let repos = repos.into_iter().map(|repo| ListedRepo { repo }).collect();
 
-    let json = base64::decode(
-        json["content"]
-            .as_str()
-            .expect("content should be a string")
-            .bytes()
-            .filter(|&byte| byte != b'\n')
-            .collect::<Vec<_>>(),
-    )
-    .expect("content was encoded as invalid base64");
-
-    let colors: HashMap<String, serde_json::Value> =
-        serde_json::from_slice(&json).expect("failed to parse color information");
-
-    Ok(colors
-        .into_iter()
-        .filter_map(|(key, value)| {
-            value["color"].as_str().map(|color| {
-                (key, {
-                    assert_eq!(
-                        color.chars().next(),
-                        Some('#'),
-                        "received non-hex formatted color"
-                    );
-                    assert_eq!(
-                        color.chars().count(),
-                        7,
-                        "received too short hex formatted color"
-                    );
-
-                    let r = u8::from_str_radix(&color[1..3], 16)
-                        .expect("encountered non-hex formatted color");
-                    let g = u8::from_str_radix(&color[3..5], 16)
-                        .expect("encountered non-hex formatted color");
-                    let b = u8::from_str_radix(&color[5..7], 16)
-                        .expect("encountered non-hex formatted color");
-
-                    rgb(r, g, b)
+                    (
+                        user,
+                        UserRepos {
+                            account,
+                            repos,
+                            gists,
+                            pinned,
+                        },
+                    )
                 })
-            })
+                .collect()
         })
-        .collect())
-}
-
-async fn fetch_all_user_repos(user: &str) -> Result<Vec<Repo>, GithubApiError> {
-    let mut repos = Vec::new();
+    });
 
-    let mut url = format!(
-        "https://api.github.com/users/{user}/repos?per_page=100&sort=created&direction=asc"
-    );
+    super::with_refetch(future)
+}
 
-    loop {
-        let response = gh::fetch(&url).await?;
+async fn fetch_account(user: &str) -> Result<Owner, GithubApiError> {
+    Ok(gh::fetch(&format!("https://api.github.com/users/{user}"))
+        .await?
+        .json::<Owner>()
+        .await
+        .expect("received unexpected json content"))
+}
 
-        repos.extend(
-            response
-                .json::<Vec<Repo>>()
-                .await
-                .expect("received unexpected json content"),
-        );
+/// Fetch the node ids of the repositories pinned to the given account's profile.
+///
+/// Returns `None` if the pinned items could not be queried.
+async fn fetch_pinned(user: &str) -> Result<Option<Vec<String>>, GithubApiError> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Data {
+        repository_owner: Option<ProfileOwner>,
+    }
 
-        if let Some(link) = response.headers().get("link") {
-            static REGEX: Lazy<Regex> = Lazy::new(|| {
-                Regex::new("<(?P<url>.+?)>; rel=\"(?P<rel>.+?)\"").expect("invalid regex")
-            });
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct ProfileOwner {
+        pinned_items: PinnedItems,
+    }
 
-            let captures = REGEX
-                .captures_iter(&link)
-                .map(|captures| {
-                    (
-                        captures.name("rel").expect("no `rel` group").as_str(),
-                        captures.name("url").expect("no `url` group").as_str(),
-                    )
-                })
-                .collect::<HashMap<_, _>>();
+    #[derive(Deserialize)]
+    struct PinnedItems {
+        nodes: Vec<PinnedItem>,
+    }
 
-            if let Some(next) = captures.get("next") {
-                trace!("paginating to next {next}");
+    #[derive(Deserialize)]
+    struct PinnedItem {
+        id: String,
+    }
 
-                url = next.to_string();
-            } else {
-                debug!("Reached end of pagination for {user}");
-                break;
+    let data = gh::graphql::<Data>(
+        r#"query($login: String!) {
+            repositoryOwner(login: $login) {
+                ... on ProfileOwner {
+                    pinnedItems(first: 6, types: REPOSITORY) {
+                        nodes { ... on Repository { id } }
+                    }
+                }
             }
-        } else {
-            debug!("No pagination for {user}");
-            break;
-        }
-    }
+        }"#,
+        serde_json::json!({ "login": user }),
+    )
+    .await?;
+
+    Ok(data.and_then(|data| data.repository_owner).map(|owner| {
+        owner
+            .pinned_items
+            .nodes
+            .into_iter()
+            .map(|item| item.id)
+            .collect()
+    }))
+}
+
+async fn fetch_all_user_repos(user: &str) -> Result<Vec<Repo>, GithubApiError> {
+    gh::fetch_all_pages(format!(
+        "https://api.github.com/users/{user}/repos?per_page=100&sort=created&direction=asc"
+    ))
+    .await
+}
 
-    Ok(repos)
+async fn fetch_all_user_gists(user: &str) -> Result<Vec<Gist>, GithubApiError> {
+    gh::fetch_all_pages(format!(
+        "https://api.github.com/users/{user}/gists?per_page=100"
+    ))
+    .await
 }
@@ -0,0 +1,63 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::Rc,
+    thread::LocalKey,
+};
+
+use dioxus::prelude::ScopeState;
+
+/// Components to re-render when some piece of global state changes
+#[derive(Default)]
+pub struct Subscribers {
+    callbacks: RefCell<HashMap<usize, Rc<dyn Fn()>>>,
+    next_id: Cell<usize>,
+}
+
+impl Subscribers {
+    /// Schedule a re-render of every subscribed component
+    pub fn notify(&self) {
+        // Callbacks are collected first so they may subscribe or unsubscribe
+        let callbacks = self
+            .callbacks
+            .borrow()
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for callback in callbacks {
+            callback();
+        }
+    }
+}
+
+/// Unsubscribes the component when its hooks are dropped
+struct Subscription {
+    subscribers: &'static LocalKey<Subscribers>,
+    id: usize,
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        self.subscribers
+            .with(|subscribers| subscribers.callbacks.borrow_mut().remove(&self.id));
+    }
+}
+
+/// Re-render the component whenever `subscribers` are notified
+pub fn use_subscription(cx: &ScopeState, subscribers: &'static LocalKey<Subscribers>) {
+    cx.use_hook(|_| {
+        let schedule_update = cx.schedule_update();
+
+        subscribers.with(|inner| {
+            let id = inner.next_id.get();
+            inner.next_id.set(id + 1);
+            inner
+                .callbacks
+                .borrow_mut()
+                .insert(id, Rc::new(move || schedule_update()));
+
+            Subscription { subscribers, id }
+        })
+    });
+}
@@ -0,0 +1,68 @@
+use std::{cell::Cell, rc::Rc};
+
+use dioxus::prelude::*;
+use gloo_events::EventListener;
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use web_sys::window;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ViewportSize {
+    pub width: f64,
+    pub height: f64,
+}
+
+fn viewport_size() -> ViewportSize {
+    let window = window().expect("window should always exist");
+    let dimension = |value: Result<JsValue, JsValue>| {
+        value
+            .ok()
+            .and_then(|value| value.as_f64())
+            .unwrap_or_default()
+    };
+
+    ViewportSize {
+        width: dimension(window.inner_width()),
+        height: dimension(window.inner_height()),
+    }
+}
+
+/// The size of the viewport, updated at most once per animation frame while resizing
+pub fn use_viewport_size(cx: &ScopeState) -> &ViewportSize {
+    let size = use_state(cx, viewport_size);
+
+    cx.use_hook(|_| {
+        let window = window().expect("window should always exist");
+        let pending = Rc::new(Cell::new(false));
+
+        EventListener::new(&window, "resize", {
+            let window = window.clone();
+            let size = size.to_owned();
+
+            move |_| {
+                // A frame has already been requested for this burst of events
+                if pending.replace(true) {
+                    return;
+                }
+
+                let frame = Closure::once_into_js({
+                    let size = size.clone();
+                    let pending = pending.clone();
+
+                    move || {
+                        pending.set(false);
+                        size.set(viewport_size());
+                    }
+                });
+
+                if window
+                    .request_animation_frame(frame.unchecked_ref())
+                    .is_err()
+                {
+                    pending.set(false);
+                }
+            }
+        })
+    });
+
+    size.get()
+}
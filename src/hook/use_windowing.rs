@@ -0,0 +1,214 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::Rc,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use dioxus::prelude::*;
+use gloo_events::EventListener;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{window, Element};
+
+/// Height assumed for items that have not been rendered yet, in pixels
+const ESTIMATED_HEIGHT: f64 = 250.0;
+/// How far past the edges of the viewport to keep items rendered, in pixels
+const OVERSCAN: f64 = 1_000.0;
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// The slice of a list to render, and the space taken by the items around it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Window {
+    pub start: usize,
+    pub end: usize,
+    /// Height of the items before `start`, in pixels
+    pub before: f64,
+    /// Height of the items from `end` onwards, in pixels
+    pub after: f64,
+}
+
+#[derive(Default)]
+struct Layout {
+    /// Distance from the top of the viewport to the top of the list
+    top: f64,
+    viewport_height: f64,
+    /// Measured heights of the items that have been rendered, by key
+    heights: HashMap<String, f64>,
+}
+
+impl Layout {
+    fn window<'k>(&self, keys: impl Iterator<Item = &'k str>) -> Window {
+        let visible_start = -self.top - OVERSCAN;
+        let visible_end = -self.top + self.viewport_height + OVERSCAN;
+
+        let mut window = Window {
+            start: 0,
+            end: 0,
+            before: 0.0,
+            after: 0.0,
+        };
+        let mut offset = 0.0;
+
+        for (index, key) in keys.enumerate() {
+            let height = self.heights.get(key).copied().unwrap_or(ESTIMATED_HEIGHT);
+
+            if offset + height < visible_start {
+                window.start = index + 1;
+                window.end = index + 1;
+                window.before += height;
+            } else if offset <= visible_end {
+                window.end = index + 1;
+            } else {
+                window.after += height;
+            }
+
+            offset += height;
+        }
+
+        window
+    }
+
+    /// Read the position of the list and the heights of its rendered items,
+    /// returning whether anything changed
+    fn measure(&mut self, list: &Element) -> bool {
+        let mut changed = false;
+
+        let top = list.get_bounding_client_rect().top();
+        let viewport_height = window()
+            .and_then(|window| window.inner_height().ok())
+            .and_then(|height| height.as_f64())
+            .unwrap_or_default();
+
+        // Only re-render for scrolling that could bring new items into view
+        if (top - self.top).abs() >= OVERSCAN / 4.0 || viewport_height != self.viewport_height {
+            self.top = top;
+            self.viewport_height = viewport_height;
+            changed = true;
+        }
+
+        if let Ok(items) = list.query_selector_all(":scope > [data-key]") {
+            for index in 0..items.length() {
+                let item = match items
+                    .get(index)
+                    .and_then(|item| item.dyn_into::<Element>().ok())
+                {
+                    Some(item) => item,
+                    None => continue,
+                };
+
+                if let Some(key) = item.get_attribute("data-key") {
+                    let height = item.get_bounding_client_rect().height();
+
+                    if self.heights.insert(key, height) != Some(height) {
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        changed
+    }
+}
+
+/// Shared between the hook and the frames it requests
+struct Measurer {
+    id: String,
+    layout: RefCell<Layout>,
+    /// Whether the list is long enough to be windowed
+    enabled: Cell<bool>,
+    /// Whether a frame has been requested and not run yet
+    pending: Cell<bool>,
+    update: Rc<dyn Fn()>,
+}
+
+impl Measurer {
+    /// Measure on the next animation frame, re-rendering if the layout changed
+    fn schedule(self: &Rc<Self>) {
+        if self.pending.replace(true) {
+            return;
+        }
+
+        let frame = Closure::once_into_js({
+            let measurer = self.clone();
+
+            move || {
+                measurer.pending.set(false);
+
+                let list = window()
+                    .and_then(|window| window.document())
+                    .and_then(|document| document.get_element_by_id(&measurer.id));
+
+                if let Some(list) = list {
+                    if measurer.layout.borrow_mut().measure(&list) {
+                        (measurer.update)();
+                    }
+                }
+            }
+        });
+
+        let requested =
+            window().map(|window| window.request_animation_frame(frame.unchecked_ref()));
+
+        if !matches!(requested, Some(Ok(_))) {
+            self.pending.set(false);
+        }
+    }
+}
+
+/// Render only the items of a long list that are near the viewport.
+///
+/// Returns an id that must be given to the list element, along with the slice
+/// of `keys` to render. Each rendered item must be a direct child of the list
+/// with its key in a `data-key` attribute, so that its height can be measured,
+/// and the list must be padded by `before` and `after` to keep its full height.
+///
+/// Every item is rendered, and nothing is measured, while `enabled` is false.
+pub fn use_windowing<'state, 'k>(
+    cx: &'state ScopeState,
+    enabled: bool,
+    keys: impl Iterator<Item = &'k str>,
+) -> (&'state str, Window) {
+    let (measurer, _listeners) = cx.use_hook(|_| {
+        let measurer = Rc::new(Measurer {
+            id: format!("window-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed)),
+            layout: RefCell::new(Layout::default()),
+            enabled: Cell::new(false),
+            pending: Cell::new(false),
+            update: cx.schedule_update(),
+        });
+
+        let window = window().expect("window should always exist");
+        let listeners = ["scroll", "resize"].map(|event| {
+            EventListener::new(&window, event, {
+                let measurer = measurer.clone();
+
+                move |_| {
+                    if measurer.enabled.get() {
+                        measurer.schedule();
+                    }
+                }
+            })
+        });
+
+        (measurer, listeners)
+    });
+
+    measurer.enabled.set(enabled);
+
+    let window = if enabled {
+        // Rendering may have changed the heights of items, or brought new ones in
+        measurer.schedule();
+
+        measurer.layout.borrow().window(keys)
+    } else {
+        Window {
+            start: 0,
+            end: keys.count(),
+            before: 0.0,
+            after: 0.0,
+        }
+    };
+
+    (measurer.id.as_str(), window)
+}
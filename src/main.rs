@@ -1,8 +1,19 @@
 use dioxus::prelude::*;
 use hook::use_repos::use_repos;
+use route::Route;
 
+mod cargo;
+mod colors;
+mod config;
+mod enrich;
 mod gh;
 mod hook;
+mod markdown;
+mod palette;
+mod query;
+mod registry;
+mod route;
+mod theme;
 mod time;
 mod view;
 
@@ -29,6 +40,13 @@ fn main() {
     })
     .expect("logger already initialized");
 
+    theme::init();
+    route::init();
+    query::init();
+
+    // The bundled colors are shown until this finishes, or if it fails
+    wasm_bindgen_futures::spawn_local(colors::refresh());
+
     dioxus::web::launch(app);
 
     // Dioxus unconditionally replaces the panic hook, so reinstate it
@@ -36,128 +54,137 @@ fn main() {
 }
 
 fn app(cx: Scope) -> Element {
-    let repos = use_repos(&cx, vec!["dusterthefirst", "thedustyard"]);
+    let route = route::use_route(&cx);
+    let repos = use_repos(&cx, config::USERS.to_vec());
 
     let repos = match repos {
-        None => rsx! {
+        None => Err(rsx! {
             div { "loading github information" }
-        },
-        Some((Ok(repos), _)) => LazyNodes::new(|cx| {
-            cx.fragment_from_iter(repos.iter().map(|(user, repos)| {
-                rsx! {
-                    section {
-                        key: "{user}",
-                        class: "user",
-
-                        h2 {
-                            class: "username",
-                            onclick: |e| panic!("{e:?}"),
-
-                            "{user}"
-                        }
-                        repos.iter().map(|repo| rsx!{
-                            view::repos::repository {
-                                key: "{repo.repo.node_id}",
-                                repo: repo
-                                saturate: false,
-                            }
-                        })
-                    }
-                }
-            }))
         }),
-        Some((Err(error), refetch)) => rsx! {
+        Some((Ok(repos), _)) => Ok(repos),
+        Some((Err(error), refetch)) => Err(rsx! {
             view::error::github_api_error {
                 error: error,
                 refetch: refetch,
             }
-        },
+        }),
     };
 
-    // let viewport = use_viewport(&cx);
-
-    cx.render(rsx! {
-        div {
-            header {
-                class: "title-card",
-                onclick: |_| panic!("test"),
-
-                div {
-                    class: "extra",
-
-                    "site licensed under "
-                    a {
-                        href: "http://mozilla.org/MPL/2.0/",
-                        title: "the mozilla public license version 2.0",
-                        target: "_blank",
-                        rel: "license",
-                        "MPL-2.0"
-                    }
-                    " on "
-                    a {
-                        href: "https://github.com/dusterthefirst/gh.dusterthefirst.com",
-                        target: "_blank",
-                        rel: "external",
-                        title: "this website's source code",
-                        "github"
-                    }
+    let page = match route {
+        Route::Overview => {
+            let filters = repos.as_ref().ok().copied().map(|repos| {
+                rsx! {
+                    view::filters::filters { repos: repos }
                 }
+            });
+            let repos = match repos {
+                Ok(repos) => LazyNodes::new(|cx| {
+                    cx.fragment_from_iter(repos.iter().map(|(user, repos)| {
+                        rsx! {
+                            view::user::user {
+                                key: "{user}",
+                                user: *user,
+                                repos: repos,
+                            }
+                        }
+                    }))
+                }),
+                Err(status) => status,
+            };
 
-                div {
-                    class: "title",
+            rsx! {
+                header {
+                    class: "title-card",
+                    onclick: |_| panic!("test"),
 
                     div {
-                        class: "main",
-                        "Zachary Kohnen"
-                    }
-                    div {
+                        class: "extra",
+
+                        "site licensed under "
                         a {
-                            href: "mailto:me@dusterthefirst.com",
-                            title: "Zachary Kohnen's email",
+                            href: "http://mozilla.org/MPL/2.0/",
+                            title: "the mozilla public license version 2.0",
                             target: "_blank",
-                            rel: "author",
-                            "me@dusterthefirst.com"
+                            rel: "license",
+                            "MPL-2.0"
                         }
-                    }
-                    div {
+                        " on "
                         a {
-                            href: "https://dusterthefirst.com",
-                            title: "Zachary Kohnen's website",
+                            href: "https://github.com/dusterthefirst/gh.dusterthefirst.com",
                             target: "_blank",
-                            rel: "author",
-                            "dusterthefirst.com"
+                            rel: "external",
+                            title: "this website's source code",
+                            "github"
                         }
                     }
-                    div {
-                        a {
-                            href: "https://gh.dusterthefirst.com",
-                            title: "Zachary Kohnen's github portfolio",
-                            target: "_self",
-                            rel: "canonical",
-                            "gh.dusterthefirst.com"
+
+                    view::profile::profile {}
+                }
+
+                view::timeline::timeline {}
+
+                filters
+
+                repos
+
+                view::contributions::external_contributions {}
+            }
+        }
+        Route::User { owner } => match repos {
+            Ok(repos) => match repos
+                .iter()
+                .find(|(user, _)| user.eq_ignore_ascii_case(&owner))
+            {
+                Some(entry) => {
+                    let (user, repos) = entry;
+
+                    rsx! {
+                        nav {
+                            class: "breadcrumbs",
+
+                            a { href: "/", "data-route": "", "overview" }
                         }
-                    }
-                    div {
-                        a {
-                            href: "https://github.com/dusterthefirst",
-                            title: "Zachary Kohnen's github",
-                            target: "_blank",
-                            rel: "external",
-                            "github.com/dusterthefirst"
+                        view::filters::filters { repos: std::slice::from_ref(entry) }
+                        view::user::user {
+                            user: *user,
+                            repos: repos,
                         }
                     }
                 }
+                None => rsx! { view::not_found::not_found {} },
+            },
+            Err(status) => status,
+        },
+        Route::Repo { owner, name } => match repos {
+            Ok(repos) => {
+                let full_name = format!("{owner}/{name}");
+
+                match repos
+                    .iter()
+                    .flat_map(|(_, repos)| &repos.repos)
+                    .find(|repo| repo.repo.full_name.eq_ignore_ascii_case(&full_name))
+                {
+                    Some(repo) => rsx! {
+                        view::detail::repository_detail { repo: repo }
+                    },
+                    None => rsx! { view::not_found::not_found {} },
+                }
             }
+            Err(status) => status,
+        },
+        Route::NotFound { .. } => rsx! { view::not_found::not_found {} },
+    };
 
-            // div {
-            //     style: "top: {viewport.scroll_y}px; position: absolute;",
+    cx.render(rsx! {
+        div {
+            div {
+                class: "settings",
 
-            //     h1 { "{viewport.scroll_y}px" }
-            //     h1 { "{viewport.client_height}px by {viewport.client_width}px" }
-            //     hr {}
-            // }
+                view::theme::theme_toggle {}
+                view::time::zone_toggle {}
+            }
 
-            repos
+            page
         }
     })
 }
@@ -0,0 +1,89 @@
+use ammonia::{Url, UrlRelative};
+use pulldown_cmark::{html, Event, Options, Parser, Tag};
+
+/// Base urls used to resolve relative links in markdown from a repository
+#[derive(Debug, Clone)]
+pub struct RepoLinks {
+    /// Root of the repository's file browser, used for links
+    blob: Url,
+    /// Root of the repository's raw files, used for images
+    raw: Url,
+    /// Directory of the markdown file, relative to the repository root
+    directory: String,
+}
+
+impl RepoLinks {
+    pub fn new(full_name: &str, branch: &str, path: &str) -> Self {
+        Self {
+            blob: Url::parse(&format!("https://github.com/{full_name}/blob/{branch}/"))
+                .expect("repository blob url should be valid"),
+            raw: Url::parse(&format!(
+                "https://raw.githubusercontent.com/{full_name}/{branch}/"
+            ))
+            .expect("repository raw url should be valid"),
+            directory: path
+                .rsplit_once('/')
+                .map(|(directory, _)| format!("{directory}/"))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Resolve a url against the given base, returning `None` if it was not relative
+    fn resolve(&self, base: &Url, url: &str) -> Option<String> {
+        if url.starts_with('#') || url.starts_with("//") || Url::parse(url).is_ok() {
+            return None;
+        }
+
+        // Github treats absolute paths as relative to the repository root
+        let path = match url.strip_prefix('/') {
+            Some(path) => path.to_string(),
+            None => format!("{}{url}", self.directory),
+        };
+
+        base.join(&path).ok().map(String::from)
+    }
+}
+
+/// Render github flavored markdown into sanitized html.
+///
+/// When `links` are provided, relative links and images are rewritten to point
+/// into the repository they came from.
+pub fn render(markdown: &str, links: Option<&RepoLinks>) -> String {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS;
+
+    let parser = Parser::new_ext(markdown, options).map(|event| match (event, links) {
+        (Event::Start(Tag::Link(kind, url, title)), Some(links)) => {
+            let url = links.resolve(&links.blob, &url).map_or(url, Into::into);
+
+            Event::Start(Tag::Link(kind, url, title))
+        }
+        (Event::Start(Tag::Image(kind, url, title)), Some(links)) => {
+            let url = links.resolve(&links.raw, &url).map_or(url, Into::into);
+
+            Event::Start(Tag::Image(kind, url, title))
+        }
+        (event, _) => event,
+    });
+
+    let mut unsanitized = String::new();
+    html::push_html(&mut unsanitized, parser);
+
+    let mut sanitizer = ammonia::Builder::default();
+
+    sanitizer
+        .add_tags(["input"])
+        .add_tag_attributes("input", ["type", "checked", "disabled"]);
+
+    // Inline html is not seen by the markdown parser, so resolve what remains
+    // against the raw files, which is where inline images need to point.
+    if let Some(links) = links {
+        if let Ok(base) = links.raw.join(&links.directory) {
+            sanitizer.url_relative(UrlRelative::RewriteWithBase(base));
+        }
+    }
+
+    sanitizer.clean(&unsanitized).to_string()
+}
@@ -0,0 +1,129 @@
+//! Foreground and background color pairs that meet the WCAG AA contrast requirements
+
+use css_colors::{hsl, percent, rgb, Color, RGB};
+
+use crate::theme::Theme;
+
+/// Minimum contrast ratio for normal text under WCAG AA
+pub const AA_CONTRAST: f32 = 4.5;
+
+/// Give up adjusting after this many steps, by which point the background is black or white
+const MAX_STEPS: u8 = 20;
+
+/// Relative luminance of a color, as defined by WCAG
+pub fn luminance(color: RGB) -> f32 {
+    let linear = |channel: u8| {
+        let channel = channel as f32 / 255.0;
+
+        if channel <= 0.03928 {
+            channel / 12.92
+        } else {
+            ((channel + 0.055) / 1.055).powf(2.4)
+        }
+    };
+
+    0.2126 * linear(color.r.as_u8())
+        + 0.7152 * linear(color.g.as_u8())
+        + 0.0722 * linear(color.b.as_u8())
+}
+
+/// Contrast ratio between two colors, from 1 to 21
+pub fn contrast(a: RGB, b: RGB) -> f32 {
+    let (a, b) = (luminance(a), luminance(b));
+    let (lighter, darker) = if a > b { (a, b) } else { (b, a) };
+
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Palette {
+    pub background: RGB,
+    pub foreground: RGB,
+    /// Background when hovered, with at least as much contrast as `background`
+    pub hover: RGB,
+    /// Outline when focused
+    pub focus: RGB,
+}
+
+impl Palette {
+    /// Build a palette around a background color, adjusting it as little as
+    /// possible until text on it is readable
+    pub fn new(background: RGB) -> Self {
+        let (white, black) = (rgb(255, 255, 255), rgb(0, 0, 0));
+
+        // Use whichever of black or white text reads best
+        let light_text = contrast(background, white) >= contrast(background, black);
+        let foreground = if light_text { white } else { black };
+
+        // Moving the background away from the text only ever increases contrast
+        let away = |color: RGB, amount| {
+            if light_text {
+                color.darken(percent(amount))
+            } else {
+                color.lighten(percent(amount))
+            }
+        };
+
+        let mut background = background;
+        for _ in 0..MAX_STEPS {
+            if contrast(background, foreground) >= AA_CONTRAST {
+                break;
+            }
+
+            background = away(background, 5);
+        }
+
+        Self {
+            background,
+            foreground,
+            hover: away(background, 8),
+            focus: foreground,
+        }
+    }
+
+    /// Build a palette for a card sitting on the page background, lifting the
+    /// color off white pages so cards read as tinted rather than heavy
+    pub fn themed(color: RGB, theme: Theme) -> Self {
+        match theme {
+            Theme::Dark => Self::new(color),
+            Theme::Light => Self::new(color.lighten(percent(20))),
+        }
+    }
+
+    /// Build a palette around a color given in hsl, with saturation and lightness in percent
+    pub fn hsl(hue: i32, saturation: u8, lightness: u8) -> Self {
+        Self::new(hsl(hue, saturation, lightness).to_rgb())
+    }
+
+    /// The palette as css custom properties, for use in a `style` attribute
+    pub fn style(&self) -> String {
+        format!(
+            "--palette-background: {}; --palette-foreground: {}; --palette-hover: {}; --palette-focus: {};",
+            self.background, self.foreground, self.hover, self.focus
+        )
+    }
+}
+
+/// The kinds of badges shown on cards, each with its own color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Badge {
+    Passing,
+    Failing,
+    InProgress,
+    Neutral,
+    Prerelease,
+    Package,
+}
+
+impl Badge {
+    pub fn palette(self) -> Palette {
+        match self {
+            Badge::Passing => Palette::hsl(130, 60, 35),
+            Badge::Failing => Palette::hsl(0, 67, 48),
+            Badge::InProgress => Palette::hsl(45, 90, 40),
+            Badge::Neutral => Palette::hsl(0, 0, 40),
+            Badge::Prerelease => Palette::hsl(30, 90, 40),
+            Badge::Package => Palette::hsl(212, 40, 35),
+        }
+    }
+}
@@ -0,0 +1,264 @@
+//! View state kept in the query string, so that it survives reloads and can be shared
+
+use std::{cell::RefCell, collections::BTreeSet};
+
+use dioxus::prelude::ScopeState;
+use gloo_events::EventListener;
+use log::warn;
+use wasm_bindgen::JsValue;
+use web_sys::{window, UrlSearchParams};
+
+use crate::hook::{
+    use_repos::{ListedRepo, Repo},
+    use_subscription::{use_subscription, Subscribers},
+};
+
+/// Order of the repository lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sort {
+    /// Oldest first, the order repositories are fetched in
+    Created,
+    /// Most recently updated first
+    Updated,
+    /// Alphabetically by name
+    Name,
+}
+
+impl Sort {
+    pub const ALL: [Sort; 3] = [Sort::Created, Sort::Updated, Sort::Name];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Sort::Created => "created",
+            Sort::Updated => "updated",
+            Sort::Name => "name",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|sort| sort.name() == name)
+    }
+}
+
+/// Which repositories to show based on whether they are forks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Forks {
+    Show,
+    Hide,
+    Only,
+}
+
+impl Forks {
+    pub const ALL: [Forks; 3] = [Forks::Show, Forks::Hide, Forks::Only];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Forks::Show => "show",
+            Forks::Hide => "hide",
+            Forks::Only => "only",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|forks| forks.name() == name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViewState {
+    pub search: String,
+    pub language: Option<String>,
+    pub sort: Sort,
+    pub forks: Forks,
+    /// Full names of the repositories with their readme open
+    pub expanded: BTreeSet<String>,
+}
+
+impl Default for ViewState {
+    fn default() -> Self {
+        Self {
+            search: String::new(),
+            language: None,
+            sort: Sort::Created,
+            forks: Forks::Show,
+            expanded: BTreeSet::new(),
+        }
+    }
+}
+
+impl ViewState {
+    /// Read the state from a query string, ignoring anything unrecognized
+    pub fn parse(query: &str) -> Self {
+        let params = match UrlSearchParams::new_with_str(query) {
+            Ok(params) => params,
+            Err(_) => return Self::default(),
+        };
+
+        Self {
+            search: params.get("q").unwrap_or_default(),
+            language: params.get("lang").filter(|language| !language.is_empty()),
+            sort: params
+                .get("sort")
+                .and_then(|sort| Sort::parse(&sort))
+                .unwrap_or(Sort::Created),
+            forks: params
+                .get("forks")
+                .and_then(|forks| Forks::parse(&forks))
+                .unwrap_or(Forks::Show),
+            expanded: params
+                .get("expand")
+                .map(|expand| {
+                    expand
+                        .split(',')
+                        .filter(|name| !name.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Write the state as a query string, including the leading `?` unless it is empty.
+    ///
+    /// Defaults are left out to keep links short.
+    pub fn query(&self) -> String {
+        let params = UrlSearchParams::new().expect("failed to create search params");
+        let default = Self::default();
+
+        if !self.search.is_empty() {
+            params.set("q", &self.search);
+        }
+        if let Some(language) = &self.language {
+            params.set("lang", language);
+        }
+        if self.sort != default.sort {
+            params.set("sort", self.sort.name());
+        }
+        if self.forks != default.forks {
+            params.set("forks", self.forks.name());
+        }
+        if !self.expanded.is_empty() {
+            let expanded = self.expanded.iter().cloned().collect::<Vec<_>>();
+
+            params.set("expand", &expanded.join(","));
+        }
+
+        let query = String::from(params.to_string());
+
+        if query.is_empty() {
+            query
+        } else {
+            format!("?{query}")
+        }
+    }
+
+    /// Whether a repository passes the search and filters
+    pub fn matches(&self, repo: &Repo) -> bool {
+        let forks = match self.forks {
+            Forks::Show => true,
+            Forks::Hide => !repo.fork,
+            Forks::Only => repo.fork,
+        };
+
+        let language = match &self.language {
+            Some(language) => repo.language.as_deref() == Some(language.as_str()),
+            None => true,
+        };
+
+        let search = self.search.trim().to_lowercase();
+        let search = search.is_empty()
+            || repo.name.to_lowercase().contains(&search)
+            || repo.description.as_deref().map_or(false, |description| {
+                description.to_lowercase().contains(&search)
+            });
+
+        forks && language && search
+    }
+
+    /// The repositories that pass the filters, in the chosen order
+    pub fn apply<'a>(&self, repos: impl Iterator<Item = &'a ListedRepo>) -> Vec<&'a ListedRepo> {
+        let mut repos = repos
+            .filter(|repo| self.matches(&repo.repo))
+            .collect::<Vec<_>>();
+
+        match self.sort {
+            Sort::Created => {}
+            Sort::Updated => repos.sort_by(|a, b| b.repo.updated_at.cmp(&a.repo.updated_at)),
+            Sort::Name => repos.sort_by_cached_key(|repo| repo.repo.name.to_lowercase()),
+        }
+
+        repos
+    }
+
+    pub fn is_expanded(&self, full_name: &str) -> bool {
+        self.expanded.contains(full_name)
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<ViewState> = RefCell::new(ViewState::parse(&location_search()));
+    static SUBSCRIBERS: Subscribers = Subscribers::default();
+}
+
+fn location_search() -> String {
+    window()
+        .and_then(|window| window.location().search().ok())
+        .unwrap_or_default()
+}
+
+/// Restore the state when moving through the history
+pub fn init() {
+    let window = window().expect("window should always exist");
+
+    EventListener::new(&window, "popstate", |_| {
+        STATE.with(|state| *state.borrow_mut() = ViewState::parse(&location_search()));
+        SUBSCRIBERS.with(Subscribers::notify);
+    })
+    .forget();
+}
+
+pub fn current() -> ViewState {
+    STATE.with(|state| state.borrow().clone())
+}
+
+/// Change the state, replacing the current history entry to match
+pub fn update(change: impl FnOnce(&mut ViewState)) {
+    let query = STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        change(&mut state);
+
+        state.query()
+    });
+
+    let window = window().expect("window should always exist");
+    let path = window
+        .location()
+        .pathname()
+        .unwrap_or_else(|_| "/".to_string());
+
+    let replaced = window.history().and_then(|history| {
+        history.replace_state_with_url(&JsValue::NULL, "", Some(&format!("{path}{query}")))
+    });
+
+    if replaced.is_err() {
+        warn!("failed to replace the history entry with {path}{query}");
+    }
+
+    SUBSCRIBERS.with(Subscribers::notify);
+}
+
+/// Open or close a repository's readme
+pub fn toggle_expanded(full_name: &str) {
+    update(|state| {
+        if !state.expanded.remove(full_name) {
+            state.expanded.insert(full_name.to_string());
+        }
+    });
+}
+
+/// The current view state, re-rendering the component whenever it changes
+pub fn use_view_state(cx: &ScopeState) -> ViewState {
+    use_subscription(cx, &SUBSCRIBERS);
+
+    current()
+}
@@ -0,0 +1,464 @@
+use std::collections::BTreeMap;
+
+use gloo_net::http::Request;
+use log::{debug, warn};
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::{
+    cargo, config,
+    gh::{self, GithubApiError},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Registry {
+    CratesIo,
+    Npm,
+    PyPi,
+}
+
+impl Registry {
+    pub const ALL: [Registry; 3] = [Registry::CratesIo, Registry::Npm, Registry::PyPi];
+
+    /// The manifest in the root of a repository that declares a package for this registry
+    pub fn manifest(self) -> &'static str {
+        match self {
+            Registry::CratesIo => "Cargo.toml",
+            Registry::Npm => "package.json",
+            Registry::PyPi => "pyproject.toml",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Registry::CratesIo => "crates.io",
+            Registry::Npm => "npm",
+            Registry::PyPi => "PyPI",
+        }
+    }
+
+    /// The human facing page of a package on this registry
+    pub fn package_url(self, package: &str) -> String {
+        match self {
+            Registry::CratesIo => format!("https://crates.io/crates/{package}"),
+            Registry::Npm => format!("https://www.npmjs.com/package/{package}"),
+            Registry::PyPi => format!("https://pypi.org/project/{package}/"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Package {
+    pub registry: Registry,
+    pub name: String,
+    pub version: String,
+    /// Total or recent downloads, if the registry reports them
+    pub downloads: Option<u64>,
+}
+
+impl Package {
+    pub fn url(&self) -> String {
+        self.registry.package_url(&self.name)
+    }
+}
+
+/// Detect the packages published from the root of a repository.
+///
+/// Failures to reach a registry are not errors, the package is simply left out.
+pub async fn detect_packages(full_name: &str) -> Result<Vec<Package>, GithubApiError> {
+    #[derive(Deserialize)]
+    struct Entry {
+        name: String,
+    }
+
+    // List the root once instead of asking for every manifest that might exist
+    let entries = match gh::fetch(&format!(
+        "https://api.github.com/repos/{full_name}/contents/"
+    ))
+    .await
+    {
+        Ok(response) => response
+            .json::<Vec<Entry>>()
+            .await
+            .expect("received unexpected json content"),
+        // Empty repositories have no contents
+        Err(GithubApiError::NotFound { .. }) => return Ok(Vec::new()),
+        Err(error) => return Err(error),
+    };
+
+    let mut packages = Vec::new();
+
+    for registry in Registry::ALL {
+        if !entries
+            .iter()
+            .any(|entry| entry.name == registry.manifest())
+        {
+            continue;
+        }
+
+        let manifest = match gh::fetch_file(full_name, registry.manifest()).await? {
+            Some(manifest) => manifest.decode(),
+            None => continue,
+        };
+
+        let name = match package_name(registry, &manifest) {
+            Some(name) => name,
+            None => {
+                debug!(
+                    "{full_name} does not publish a package to {}",
+                    registry.name()
+                );
+                continue;
+            }
+        };
+
+        if let Some(package) = query_registry(registry, name, full_name).await {
+            packages.push(package);
+        }
+    }
+
+    Ok(packages)
+}
+
+/// Parse the name of the published package out of a manifest
+fn package_name(registry: Registry, manifest: &[u8]) -> Option<String> {
+    match registry {
+        Registry::CratesIo => {
+            #[derive(Deserialize)]
+            struct Manifest {
+                package: Option<ManifestPackage>,
+            }
+
+            #[derive(Deserialize)]
+            struct ManifestPackage {
+                name: String,
+                publish: Option<toml::Value>,
+            }
+
+            let package = toml::from_slice::<Manifest>(manifest).ok()?.package?;
+
+            cargo::publishable(package.publish.as_ref()).then(|| package.name)
+        }
+        Registry::Npm => {
+            #[derive(Deserialize)]
+            struct Manifest {
+                name: Option<String>,
+                #[serde(default)]
+                private: bool,
+            }
+
+            let manifest = serde_json::from_slice::<Manifest>(manifest).ok()?;
+
+            manifest.name.filter(|_| !manifest.private)
+        }
+        Registry::PyPi => {
+            #[derive(Deserialize)]
+            struct Manifest {
+                project: Option<Project>,
+                tool: Option<Tool>,
+            }
+
+            #[derive(Deserialize)]
+            struct Project {
+                name: String,
+            }
+
+            #[derive(Deserialize)]
+            struct Tool {
+                poetry: Option<Project>,
+            }
+
+            let manifest = toml::from_slice::<Manifest>(manifest).ok()?;
+
+            manifest
+                .project
+                .or_else(|| manifest.tool.and_then(|tool| tool.poetry))
+                .map(|project| project.name)
+        }
+    }
+}
+
+/// Whether a link from a registry points at a github repository, allowing for
+/// the many ways of writing one, ie `git+https://github.com/owner/repo.git`,
+/// `git@github.com:owner/repo` or npm's `github:owner/repo` and `owner/repo` shorthands
+fn links_to(link: &str, full_name: &str) -> bool {
+    let link = link.trim().to_lowercase();
+    let link = link.strip_prefix("git+").unwrap_or(&link);
+    let link = link
+        .split_once("://")
+        .map_or(link, |(_, rest)| rest)
+        .trim_start_matches("git@")
+        .trim_start_matches("www.")
+        .replacen("github.com:", "github.com/", 1);
+    let link = match link.strip_prefix("github:") {
+        Some(shorthand) => format!("github.com/{shorthand}"),
+        // Account names cannot contain dots, unlike the host of any other link
+        None if is_bare_shorthand(&link) => format!("github.com/{link}"),
+        None => link,
+    };
+
+    let repo = format!("github.com/{}", full_name.to_lowercase());
+    let path = link.trim_end_matches('/').trim_end_matches(".git");
+
+    // Links may point into the repository, ie at the directory of a workspace member
+    path == repo || path.starts_with(&format!("{repo}/"))
+}
+
+/// Whether a link is npm's `owner/repo` shorthand for a github repository
+fn is_bare_shorthand(link: &str) -> bool {
+    match link.split_once('/') {
+        Some((owner, repo)) => {
+            !owner.is_empty()
+                && !repo.is_empty()
+                && !repo.contains('/')
+                && !link.contains(':')
+                && !owner.contains(['.', '@'])
+        }
+        None => false,
+    }
+}
+
+/// Look up the latest version of a package, `None` if the registry does not know
+/// of it or the package does not link back to the repository it was found in
+pub async fn query_registry(registry: Registry, name: String, full_name: &str) -> Option<Package> {
+    let (version, downloads, links): (String, Option<u64>, Vec<String>) = match registry {
+        Registry::CratesIo => {
+            #[derive(Deserialize)]
+            struct Response {
+                #[serde(rename = "crate")]
+                krate: Crate,
+            }
+
+            #[derive(Deserialize)]
+            struct Crate {
+                max_version: String,
+                downloads: u64,
+                repository: Option<String>,
+                homepage: Option<String>,
+            }
+
+            let response =
+                fetch_json::<Response>(&format!("{}/crates/{name}", config::CRATES_IO_API)).await?;
+            let krate = response.krate;
+
+            (
+                krate.max_version,
+                Some(krate.downloads),
+                [krate.repository, krate.homepage]
+                    .into_iter()
+                    .flatten()
+                    .collect(),
+            )
+        }
+        Registry::Npm => {
+            #[derive(Deserialize)]
+            struct Latest {
+                version: String,
+                repository: Option<Repository>,
+                homepage: Option<String>,
+            }
+
+            /// Either a url or shorthand, or an object with one
+            #[derive(Deserialize)]
+            #[serde(untagged)]
+            enum Repository {
+                Link(String),
+                Object { url: String },
+            }
+
+            #[derive(Deserialize)]
+            struct Downloads {
+                downloads: u64,
+            }
+
+            // Scoped packages keep their slash encoded in the registry api
+            let latest = fetch_json::<Latest>(&format!(
+                "{}/{}/latest",
+                config::NPM_REGISTRY,
+                name.replace('/', "%2F")
+            ))
+            .await?;
+            let downloads = fetch_json::<Downloads>(&format!(
+                "{}/downloads/point/last-month/{name}",
+                config::NPM_DOWNLOADS_API
+            ))
+            .await;
+
+            let repository = latest.repository.map(|repository| match repository {
+                Repository::Link(url) | Repository::Object { url } => url,
+            });
+
+            (
+                latest.version,
+                downloads.map(|downloads| downloads.downloads),
+                [repository, latest.homepage]
+                    .into_iter()
+                    .flatten()
+                    .collect(),
+            )
+        }
+        Registry::PyPi => {
+            #[derive(Deserialize)]
+            struct Response {
+                info: Info,
+            }
+
+            #[derive(Deserialize)]
+            struct Info {
+                version: String,
+                home_page: Option<String>,
+                #[serde(default)]
+                project_urls: Option<BTreeMap<String, String>>,
+            }
+
+            let response =
+                fetch_json::<Response>(&format!("{}/{name}/json", config::PYPI_API)).await?;
+
+            let info = response.info;
+            let links = info
+                .project_urls
+                .unwrap_or_default()
+                .into_values()
+                .chain(info.home_page)
+                .collect::<Vec<_>>();
+
+            // PyPI no longer reports download counts
+            (info.version, None, links)
+        }
+    };
+
+    if !links.iter().any(|link| links_to(link, full_name)) {
+        debug!(
+            "{name} on {} does not link to {full_name}, so is a different package",
+            registry.name()
+        );
+
+        return None;
+    }
+
+    Some(Package {
+        registry,
+        name,
+        version,
+        downloads,
+    })
+}
+
+async fn fetch_json<T: DeserializeOwned>(url: &str) -> Option<T> {
+    let response = match Request::get(url).send().await {
+        Ok(response) => response,
+        Err(error) => {
+            warn!("Failed to reach package registry at {url}: {error}");
+
+            return None;
+        }
+    };
+
+    if !response.ok() {
+        debug!(
+            "Package registry responded with {} for {url}",
+            response.status()
+        );
+
+        return None;
+    }
+
+    match response.json::<T>().await {
+        Ok(json) => Some(json),
+        Err(error) => {
+            warn!("Package registry responded with unexpected content for {url}: {error}");
+
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn links_to_accepts_every_way_of_writing_the_repository() {
+        for link in [
+            "https://github.com/Owner/Repo",
+            "https://www.github.com/owner/repo/",
+            "git+https://github.com/owner/repo.git",
+            "git://github.com/owner/repo.git",
+            "git@github.com:owner/repo.git",
+            "ssh://git@github.com/owner/repo",
+            "github:owner/repo",
+            "owner/repo",
+            "https://github.com/owner/repo/tree/main/crates/member",
+        ] {
+            assert!(
+                links_to(link, "owner/repo"),
+                "{link} should link to owner/repo"
+            );
+        }
+    }
+
+    #[test]
+    fn links_to_rejects_other_repositories() {
+        for link in [
+            "https://github.com/owner/repository",
+            "https://github.com/other/repo",
+            "https://gitlab.com/owner/repo",
+            "gitlab:owner/repo",
+            "example.com/repo",
+            "https://owner.github.io/repo",
+        ] {
+            assert!(
+                !links_to(link, "owner/repo"),
+                "{link} should not link to owner/repo"
+            );
+        }
+    }
+
+    #[test]
+    fn cargo_manifests_name_publishable_packages() {
+        let name = |manifest: &str| package_name(Registry::CratesIo, manifest.as_bytes());
+
+        assert_eq!(
+            name("[package]\nname = \"krate\"\nversion = \"0.1.0\"\n"),
+            Some("krate".to_string())
+        );
+        assert_eq!(
+            name("[package]\nname = \"krate\"\npublish = [\"crates-io\"]\n"),
+            Some("krate".to_string())
+        );
+        assert_eq!(name("[package]\nname = \"krate\"\npublish = false\n"), None);
+        assert_eq!(
+            name("[package]\nname = \"krate\"\npublish = [\"my-registry\"]\n"),
+            None
+        );
+        assert_eq!(name("[workspace]\nmembers = [\"krate\"]\n"), None);
+        assert_eq!(name("not toml"), None);
+    }
+
+    #[test]
+    fn npm_manifests_name_public_packages() {
+        let name = |manifest: &str| package_name(Registry::Npm, manifest.as_bytes());
+
+        assert_eq!(
+            name(r#"{ "name": "@scope/package", "version": "1.0.0" }"#),
+            Some("@scope/package".to_string())
+        );
+        assert_eq!(name(r#"{ "name": "package", "private": true }"#), None);
+        assert_eq!(name(r#"{ "private": false }"#), None);
+        assert_eq!(name("not json"), None);
+    }
+
+    #[test]
+    fn python_manifests_name_projects() {
+        let name = |manifest: &str| package_name(Registry::PyPi, manifest.as_bytes());
+
+        assert_eq!(
+            name("[project]\nname = \"package\"\n"),
+            Some("package".to_string())
+        );
+        assert_eq!(
+            name("[tool.poetry]\nname = \"package\"\n"),
+            Some("package".to_string())
+        );
+        assert_eq!(name("[build-system]\nrequires = []\n"), None);
+    }
+}
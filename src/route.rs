@@ -0,0 +1,155 @@
+//! Client side routing on top of the history api.
+//!
+//! Links opt into routing with a `data-route` attribute, everything else is
+//! left to the browser.
+
+use std::cell::RefCell;
+
+use dioxus::prelude::ScopeState;
+use gloo_events::EventListener;
+use log::warn;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{window, Element, MouseEvent};
+
+use crate::{
+    hook::use_subscription::{use_subscription, Subscribers},
+    query,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Route {
+    /// Every account on one page
+    Overview,
+    /// A single account
+    User {
+        owner: String,
+    },
+    /// Everything known about a single repository
+    Repo {
+        owner: String,
+        name: String,
+    },
+    NotFound {
+        path: String,
+    },
+}
+
+impl Route {
+    pub fn parse(path: &str) -> Self {
+        let segments = path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>();
+
+        match segments.as_slice() {
+            [] => Route::Overview,
+            [owner] => Route::User {
+                owner: owner.to_string(),
+            },
+            [owner, name] => Route::Repo {
+                owner: owner.to_string(),
+                name: name.to_string(),
+            },
+            _ => Route::NotFound {
+                path: path.to_string(),
+            },
+        }
+    }
+
+    pub fn path(&self) -> String {
+        match self {
+            Route::Overview => "/".to_string(),
+            Route::User { owner } => format!("/{owner}"),
+            Route::Repo { owner, name } => format!("/{owner}/{name}"),
+            Route::NotFound { path } => path.clone(),
+        }
+    }
+}
+
+thread_local! {
+    static ROUTE: RefCell<Route> = RefCell::new(Route::parse(&location_path()));
+    static SUBSCRIBERS: Subscribers = Subscribers::default();
+}
+
+fn location_path() -> String {
+    window()
+        .and_then(|window| window.location().pathname().ok())
+        .unwrap_or_else(|| "/".to_string())
+}
+
+fn set(route: Route) {
+    ROUTE.with(|current| *current.borrow_mut() = route);
+    SUBSCRIBERS.with(Subscribers::notify);
+}
+
+/// Follow the browser's back and forward buttons and take over routed links
+pub fn init() {
+    let window = window().expect("window should always exist");
+    let document = window.document().expect("document should always exist");
+
+    EventListener::new(&window, "popstate", |_| set(Route::parse(&location_path()))).forget();
+
+    EventListener::new(&document, "click", |event| {
+        let event = match event.dyn_ref::<MouseEvent>() {
+            Some(event) => event,
+            None => return,
+        };
+
+        // Leave opening in a new tab or window to the browser
+        if event.default_prevented()
+            || event.button() != 0
+            || event.ctrl_key()
+            || event.meta_key()
+            || event.shift_key()
+            || event.alt_key()
+        {
+            return;
+        }
+
+        let href = event
+            .target()
+            .and_then(|target| target.dyn_into::<Element>().ok())
+            .and_then(|target| target.closest("a[data-route]").ok().flatten())
+            .and_then(|anchor| anchor.get_attribute("href"));
+
+        if let Some(href) = href {
+            event.prevent_default();
+            navigate(Route::parse(&href));
+        }
+    })
+    .forget();
+}
+
+pub fn current() -> Route {
+    ROUTE.with(|route| route.borrow().clone())
+}
+
+/// Push a new entry onto the history and render it, keeping the view state
+pub fn navigate(route: Route) {
+    if route == current() {
+        return;
+    }
+
+    let window = window().expect("window should always exist");
+
+    let url = format!("{}{}", route.path(), query::current().query());
+
+    let pushed = window
+        .history()
+        .and_then(|history| history.push_state_with_url(&JsValue::NULL, "", Some(&url)));
+
+    if pushed.is_err() {
+        warn!("failed to push {url} onto the history");
+    }
+
+    window.scroll_to_with_x_and_y(0.0, 0.0);
+
+    set(route);
+}
+
+/// The current route, re-rendering the component whenever it changes
+pub fn use_route(cx: &ScopeState) -> Route {
+    use_subscription(cx, &SUBSCRIBERS);
+
+    current()
+}
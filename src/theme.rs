@@ -0,0 +1,229 @@
+//! Light and dark themes, following the system preference unless the user picks one
+
+use std::cell::RefCell;
+
+use dioxus::prelude::ScopeState;
+use gloo_events::EventListener;
+use log::warn;
+use web_sys::{window, MediaQueryList, Storage};
+
+use crate::hook::use_subscription::{use_subscription, Subscribers};
+
+const STORAGE_KEY: &str = concat!(env!("CARGO_PKG_NAME"), "-theme");
+const DARK_QUERY: &str = "(prefers-color-scheme: dark)";
+
+/// The theme the page is currently rendered in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Theme {
+    Light,
+    Dark,
+}
+
+impl Theme {
+    pub fn name(self) -> &'static str {
+        match self {
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+        }
+    }
+
+    /// Css custom properties used by `main.scss`
+    fn tokens(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Theme::Light => &[
+                ("--background", "white"),
+                ("--foreground", "hsl(0, 0%, 10%)"),
+                ("--muted", "hsl(0, 0%, 35%)"),
+                ("--faint", "hsl(0, 0%, 45%)"),
+                ("--surface", "hsl(0, 0%, 90%)"),
+                ("--surface-hover", "hsl(0, 0%, 85%)"),
+                ("--border", "hsl(0, 0%, 75%)"),
+                ("--link", "hsl(212, 90%, 40%)"),
+                ("--link-muted", "hsl(212, 40%, 40%)"),
+                ("--highlight", "hsl(45, 90%, 40%)"),
+                ("--calendar-0", "#ebedf0"),
+                ("--calendar-1", "#9be9a8"),
+                ("--calendar-2", "#40c463"),
+                ("--calendar-3", "#30a14e"),
+                ("--calendar-4", "#216e39"),
+            ],
+            Theme::Dark => &[
+                ("--background", "#000"),
+                ("--foreground", "white"),
+                ("--muted", "hsl(0, 0%, 60%)"),
+                ("--faint", "hsl(0, 0%, 50%)"),
+                ("--surface", "hsl(0, 0%, 20%)"),
+                ("--surface-hover", "hsl(0, 0%, 15%)"),
+                ("--border", "hsl(0, 0%, 30%)"),
+                ("--link", "hsl(212, 90%, 50%)"),
+                ("--link-muted", "hsl(212, 40%, 50%)"),
+                ("--highlight", "hsl(45, 90%, 50%)"),
+                ("--calendar-0", "hsl(0, 0%, 15%)"),
+                ("--calendar-1", "#0e4429"),
+                ("--calendar-2", "#006d32"),
+                ("--calendar-3", "#26a641"),
+                ("--calendar-4", "#39d353"),
+            ],
+        }
+    }
+
+    /// The tokens as a `style` attribute for the document element
+    fn style(self) -> String {
+        self.tokens()
+            .iter()
+            .map(|(name, value)| format!("{name}: {value}; "))
+            .chain([format!("color-scheme: {};", self.name())])
+            .collect()
+    }
+}
+
+/// The theme chosen by the user
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preference {
+    System,
+    Light,
+    Dark,
+}
+
+impl Preference {
+    pub const ALL: [Preference; 3] = [Preference::System, Preference::Light, Preference::Dark];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Preference::System => "system",
+            Preference::Light => "light",
+            Preference::Dark => "dark",
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|preference| preference.name() == name)
+    }
+}
+
+struct State {
+    preference: Preference,
+    system: Theme,
+    _listener: Option<EventListener>,
+}
+
+impl State {
+    fn load() -> Self {
+        let preference = storage()
+            .and_then(|storage| storage.get_item(STORAGE_KEY).ok().flatten())
+            .and_then(|name| Preference::parse(&name))
+            .unwrap_or(Preference::System);
+
+        let query = window().and_then(|window| window.match_media(DARK_QUERY).ok().flatten());
+        let system = query.as_ref().map_or(Theme::Dark, system_theme);
+
+        // Follow the system if it changes while the page is open
+        let listener = query.map(|query| {
+            EventListener::new(&query, "change", {
+                let query = query.clone();
+
+                move |_| {
+                    let system = system_theme(&query);
+
+                    update(|state| state.system = system);
+                }
+            })
+        });
+
+        Self {
+            preference,
+            system,
+            _listener: listener,
+        }
+    }
+
+    fn theme(&self) -> Theme {
+        match self.preference {
+            Preference::System => self.system,
+            Preference::Light => Theme::Light,
+            Preference::Dark => Theme::Dark,
+        }
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<State> = RefCell::new(State::load());
+    static SUBSCRIBERS: Subscribers = Subscribers::default();
+}
+
+fn storage() -> Option<Storage> {
+    window()?.local_storage().ok().flatten()
+}
+
+fn system_theme(query: &MediaQueryList) -> Theme {
+    if query.matches() {
+        Theme::Dark
+    } else {
+        Theme::Light
+    }
+}
+
+/// Write the theme's tokens onto the document element
+fn apply(theme: Theme) {
+    let root = window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.document_element());
+
+    if let Some(root) = root {
+        if root.set_attribute("style", &theme.style()).is_err()
+            || root.set_attribute("data-theme", theme.name()).is_err()
+        {
+            warn!("failed to apply the {} theme", theme.name());
+        }
+    }
+}
+
+fn update(change: impl FnOnce(&mut State)) {
+    let theme = STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        change(&mut state);
+
+        state.theme()
+    });
+
+    apply(theme);
+    SUBSCRIBERS.with(Subscribers::notify);
+}
+
+/// Apply the stored or system theme, before anything is rendered
+pub fn init() {
+    apply(current());
+}
+
+pub fn current() -> Theme {
+    STATE.with(|state| state.borrow().theme())
+}
+
+pub fn preference() -> Preference {
+    STATE.with(|state| state.borrow().preference)
+}
+
+/// Switch to and remember a new preference
+pub fn set_preference(preference: Preference) {
+    if let Some(storage) = storage() {
+        let stored = match preference {
+            Preference::System => storage.remove_item(STORAGE_KEY),
+            preference => storage.set_item(STORAGE_KEY, preference.name()),
+        };
+
+        if stored.is_err() {
+            warn!("failed to store theme preference");
+        }
+    }
+
+    update(|state| state.preference = preference);
+}
+
+/// The active theme, re-rendering the component whenever it changes
+pub fn use_theme(cx: &ScopeState) -> Theme {
+    use_subscription(cx, &SUBSCRIBERS);
+
+    current()
+}
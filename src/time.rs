@@ -1,7 +1,206 @@
-use js_sys::Date;
-use time::OffsetDateTime;
+//! Current time, and how times are shown to the visitor
+
+use std::cell::{Cell, RefCell};
+
+use dioxus::prelude::ScopeState;
+use gloo_timers::callback::Interval;
+use js_sys::{Date, Intl, Reflect};
+use log::warn;
+use time::{
+    format_description::FormatItem, macros::format_description, Duration, OffsetDateTime, UtcOffset,
+};
+use wasm_bindgen::JsValue;
+use web_sys::{window, Storage};
+
+use crate::hook::use_subscription::{use_subscription, Subscribers};
+
+const STORAGE_KEY: &str = concat!(env!("CARGO_PKG_NAME"), "-time-zone");
+
+/// How often relative times are brought up to date, in milliseconds
+const TICK_INTERVAL: u32 = 30_000;
+
+/// How far ahead of the visitor's clock github's may be before times show as in the future
+const CLOCK_SKEW: Duration = Duration::minutes(5);
+
+thread_local! {
+    static TICK: Subscribers = Subscribers::default();
+    /// Started by the first component that shows a relative time
+    static TIMER: RefCell<Option<Interval>> = RefCell::new(None);
+
+    static ZONE: Cell<Zone> = Cell::new(stored_zone());
+    static ZONE_SUBSCRIBERS: Subscribers = Subscribers::default();
+    /// The IANA name of the browser's time zone, ie `Europe/Berlin`
+    static LOCAL_ZONE_NAME: Option<String> = local_zone_name();
+}
+
+/// The time zone times are shown in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Zone {
+    /// The browser's time zone
+    Local,
+    Utc,
+}
+
+impl Zone {
+    pub const ALL: [Zone; 2] = [Zone::Local, Zone::Utc];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Zone::Local => "local",
+            Zone::Utc => "utc",
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|zone| zone.name() == name)
+    }
+}
+
+fn storage() -> Option<Storage> {
+    window()?.local_storage().ok().flatten()
+}
+
+fn stored_zone() -> Zone {
+    storage()
+        .and_then(|storage| storage.get_item(STORAGE_KEY).ok().flatten())
+        .and_then(|name| Zone::parse(&name))
+        .unwrap_or(Zone::Local)
+}
+
+fn local_zone_name() -> Option<String> {
+    let options = Intl::DateTimeFormat::default().resolved_options();
+
+    Reflect::get(&options, &JsValue::from_str("timeZone"))
+        .ok()?
+        .as_string()
+}
+
+/// The browser's offset from UTC at the given time, which differs across daylight saving
+pub fn local_offset(time: OffsetDateTime) -> UtcOffset {
+    let date = Date::new(&JsValue::from_f64(time.unix_timestamp() as f64 * 1000.0));
+    // Minutes behind UTC, so west of greenwich is positive
+    let seconds = -(date.get_timezone_offset() as i32) * 60;
+
+    UtcOffset::from_whole_seconds(seconds).unwrap_or(UtcOffset::UTC)
+}
+
+pub fn zone() -> Zone {
+    ZONE.with(Cell::get)
+}
+
+/// Show times in and remember a new zone
+pub fn set_zone(zone: Zone) {
+    if let Some(storage) = storage() {
+        let stored = match zone {
+            Zone::Local => storage.remove_item(STORAGE_KEY),
+            zone => storage.set_item(STORAGE_KEY, zone.name()),
+        };
+
+        if stored.is_err() {
+            warn!("failed to store time zone preference");
+        }
+    }
+
+    ZONE.with(|current| current.set(zone));
+    ZONE_SUBSCRIBERS.with(Subscribers::notify);
+}
+
+/// The zone times are shown in, re-rendering the component whenever it changes
+pub fn use_zone(cx: &ScopeState) -> Zone {
+    use_subscription(cx, &ZONE_SUBSCRIBERS);
+
+    zone()
+}
+
+/// Name of the zone times are shown in, ie `UTC`, `Europe/Berlin` or `UTC+02:00`
+/// if the browser does not know the name of its zone
+pub fn zone_name(time: OffsetDateTime) -> String {
+    match zone() {
+        Zone::Utc => "UTC".to_string(),
+        Zone::Local => LOCAL_ZONE_NAME.with(Clone::clone).unwrap_or_else(|| {
+            let offset = local_offset(time)
+                .format(format_description!(
+                    "[offset_hour sign:mandatory]:[offset_minute]"
+                ))
+                .expect("failed to format offset");
+
+            format!("UTC{offset}")
+        }),
+    }
+}
+
+/// Format a time in the zone times are shown in, followed by the name of the zone
+pub fn format_in_zone(time: OffsetDateTime, description: &[FormatItem<'_>]) -> String {
+    let offset = match zone() {
+        Zone::Utc => UtcOffset::UTC,
+        Zone::Local => local_offset(time),
+    };
+
+    let formatted = time
+        .to_offset(offset)
+        .format(description)
+        .expect("failed to format time");
+
+    format!("{formatted} {}", zone_name(time))
+}
 
 pub fn now() -> OffsetDateTime {
     OffsetDateTime::from_unix_timestamp((Date::now() as u64 / 1000) as _)
         .expect("unable to create time from epoch")
 }
+
+/// Describe how far away a time is, ie `3 days ago` or `in 2 hours`
+pub fn relative(time: OffsetDateTime) -> String {
+    let difference = now() - time;
+
+    // Small differences in the future are more likely a slow clock than a real future time
+    let (elapsed, future) = if difference >= -CLOCK_SKEW {
+        (difference.max(Duration::ZERO), false)
+    } else {
+        (-difference, true)
+    };
+
+    let (count, unit) = if elapsed.whole_minutes() < 1 {
+        return "just now".to_string();
+    } else if elapsed.whole_hours() < 1 {
+        (elapsed.whole_minutes(), "minute")
+    } else if elapsed.whole_days() < 1 {
+        (elapsed.whole_hours(), "hour")
+    } else if elapsed.whole_days() < 30 {
+        (elapsed.whole_days(), "day")
+    } else if elapsed.whole_days() < 365 {
+        (elapsed.whole_days() / 30, "month")
+    } else {
+        (elapsed.whole_days() / 365, "year")
+    };
+
+    let plural = if count == 1 { "" } else { "s" };
+
+    if future {
+        format!("in {count} {unit}{plural}")
+    } else {
+        format!("{count} {unit}{plural} ago")
+    }
+}
+
+/// The full date and time, in the zone times are shown in
+pub fn exact(time: OffsetDateTime) -> String {
+    format_in_zone(
+        time,
+        format_description!("[year]-[month]-[day] [hour]:[minute]:[second]"),
+    )
+}
+
+/// Re-render the component every so often, to keep its relative times up to date.
+///
+/// All components share a single timer, and only those using this hook are
+/// re-rendered when it fires.
+pub fn use_ticker(cx: &ScopeState) {
+    use_subscription(cx, &TICK);
+
+    TIMER.with(|timer| {
+        timer
+            .borrow_mut()
+            .get_or_insert_with(|| Interval::new(TICK_INTERVAL, || TICK.with(Subscribers::notify)));
+    });
+}
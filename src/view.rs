@@ -1,2 +1,23 @@
+pub mod avatar;
+pub mod calendar;
+pub mod cargo;
+pub mod ci;
+pub mod contributions;
+pub mod detail;
 pub mod error;
+pub mod filters;
+pub mod fork;
+pub mod gists;
+pub mod languages;
+pub mod not_found;
+pub mod packages;
+pub mod profile;
+pub mod readme;
+pub mod release;
 pub mod repos;
+pub mod sparkline;
+pub mod theme;
+pub mod time;
+pub mod timeline;
+pub mod user;
+pub mod window;
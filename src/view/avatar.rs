@@ -0,0 +1,61 @@
+use dioxus::prelude::*;
+
+use crate::{
+    enrich::Ticket,
+    hook::{use_contributors::use_contributors, use_repos::Repo},
+};
+
+/// Size avatars are requested at, in pixels, twice their displayed size for high dpi screens
+const AVATAR_SIZE: u32 = 64;
+
+/// Ask github for a thumbnail instead of the full size avatar
+fn thumbnail(avatar_url: &str) -> String {
+    let separator = if avatar_url.contains('?') { '&' } else { '?' };
+
+    format!("{avatar_url}{separator}s={AVATAR_SIZE}")
+}
+
+#[inline_props]
+pub fn avatar<'a>(cx: Scope, avatar_url: &'a str, login: &'a str, title: String) -> Element {
+    let src = thumbnail(avatar_url);
+
+    cx.render(rsx! {
+        img {
+            class: "avatar",
+            src: "{src}",
+            alt: "{login}",
+            title: "{title}",
+            "loading": "lazy",
+        }
+    })
+}
+
+#[inline_props]
+pub fn contributors<'a>(cx: Scope, repo: &'a Repo, ticket: &'a Ticket) -> Element {
+    let contributors = use_contributors(&cx, repo, ticket)?;
+
+    cx.render(rsx! {
+        div {
+            class: "contributors",
+
+            contributors.iter().map(|contributor| {
+                let plural = if contributor.contributions == 1 { "" } else { "s" };
+
+                rsx! {
+                    a {
+                        key: "{contributor.login}",
+                        href: "{contributor.html_url}",
+                        target: "_blank",
+                        rel: "external",
+
+                        avatar {
+                            avatar_url: &contributor.avatar_url,
+                            login: &contributor.login,
+                            title: format!("{}: {} commit{plural}", contributor.login, contributor.contributions),
+                        }
+                    }
+                }
+            })
+        }
+    })
+}
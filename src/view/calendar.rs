@@ -0,0 +1,133 @@
+use dioxus::prelude::*;
+
+use crate::hook::use_contributions::{use_contributions, Contributions};
+
+const CELL: u32 = 10;
+const GAP: u32 = 3;
+
+/// Draw the contribution calendar as a grid of weeks, with a tooltip for each day
+fn calendar_svg(contributions: &Contributions) -> String {
+    let width = contributions.weeks.len() as u32 * (CELL + GAP);
+    let height = 7 * (CELL + GAP);
+
+    let days = contributions
+        .weeks
+        .iter()
+        .enumerate()
+        .flat_map(|(week, days)| {
+            days.contribution_days.iter().map(move |day| {
+                let x = week as u32 * (CELL + GAP);
+                let y = day.weekday as u32 * (CELL + GAP);
+                let plural = if day.contribution_count == 1 { "" } else { "s" };
+
+                format!(
+                    r#"<rect x="{x}" y="{y}" width="{CELL}" height="{CELL}" rx="2" fill="var(--calendar-{level})"><title>{count} contribution{plural} on {date}</title></rect>"#,
+                    level = day.contribution_level.index(),
+                    count = day.contribution_count,
+                    date = day.date,
+                )
+            })
+        })
+        .collect::<String>();
+
+    format!(r#"<svg viewBox="0 0 {width} {height}" width="{width}" height="{height}">{days}</svg>"#)
+}
+
+#[inline_props]
+pub fn contribution_calendar(cx: Scope, user: &'static str) -> Element {
+    let year = use_state(&cx, || None);
+    let contributions = use_contributions(&cx, user, *year.get());
+    // Kept from the last calendar that loaded, so a year that fails can be left
+    let years = use_ref(&cx, Vec::new);
+
+    let period = match year.get() {
+        Some(year) => format!("in {year}"),
+        None => "in the last year".to_string(),
+    };
+
+    let (summary, grid) = match contributions {
+        None => {
+            return cx.render(rsx! {
+                div { class: "calendar loading", "loading contributions" }
+            })
+        }
+        Some(Ok(Some(contributions))) => {
+            if *years.read() != contributions.years {
+                *years.write_silent() = contributions.years.clone();
+            }
+
+            let total = contributions.total();
+            let longest_streak = contributions.longest_streak();
+            let svg = calendar_svg(contributions);
+
+            (
+                rsx! {
+                    div {
+                        class: "summary",
+
+                        span { "{total} contributions {period}" }
+                        span { "longest streak: {longest_streak} days" }
+                        year.get().is_none().then(|| {
+                            let current_streak = contributions.current_streak();
+
+                            rsx! { span { "current streak: {current_streak} days" } }
+                        })
+                    }
+                },
+                rsx! {
+                    div {
+                        class: "grid",
+                        dangerous_inner_html: "{svg}",
+                    }
+                },
+            )
+        }
+        // The calendar is only available to authenticated users, and is not
+        // important enough to show an error for.
+        Some(Ok(None) | Err(_)) if years.read().is_empty() => return None,
+        Some(Ok(None) | Err(_)) => (
+            rsx! {
+                div { class: "summary" }
+            },
+            rsx! {
+                div { class: "grid missing", "no contributions could be loaded {period}" }
+            },
+        ),
+    };
+
+    let last_year_class = if year.get().is_none() { "selected" } else { "" };
+    let years = years.read().clone();
+
+    cx.render(rsx! {
+        div {
+            class: "calendar",
+
+            summary
+            grid
+
+            div {
+                class: "years",
+
+                button {
+                    class: "{last_year_class}",
+                    onclick: move |_| year.set(None),
+
+                    "last year"
+                }
+                years.into_iter().map(|contribution_year| {
+                    let selected = if *year.get() == Some(contribution_year) { "selected" } else { "" };
+
+                    rsx! {
+                        button {
+                            key: "{contribution_year}",
+                            class: "{selected}",
+                            onclick: move |_| year.set(Some(contribution_year)),
+
+                            "{contribution_year}"
+                        }
+                    }
+                })
+            }
+        }
+    })
+}
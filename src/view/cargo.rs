@@ -0,0 +1,119 @@
+use dioxus::prelude::*;
+
+use crate::{
+    cargo::CrateInfo,
+    enrich::Ticket,
+    hook::{use_crates::use_crates, use_repos::Repo},
+    palette::Badge,
+};
+
+/// Expandable panel listing the crates of a rust repository
+#[inline_props]
+pub fn crate_panel<'a>(cx: Scope, repo: &'a Repo, ticket: &'a Ticket) -> Element {
+    let expanded = use_state(&cx, || false);
+    let label = if *expanded.get() {
+        "hide crates"
+    } else {
+        "show crates"
+    };
+
+    cx.render(rsx! {
+        div {
+            class: "crates",
+
+            button {
+                class: "expand",
+                onclick: move |_| expanded.modify(|expanded| !expanded),
+
+                "{label}"
+            }
+            expanded.get().then(|| rsx! {
+                crate_list { repo: repo, ticket: ticket }
+            })
+        }
+    })
+}
+
+#[inline_props]
+fn crate_list<'a>(cx: Scope, repo: &'a Repo, ticket: &'a Ticket) -> Element {
+    let crates = match use_crates(&cx, repo, ticket) {
+        None => return cx.render(rsx! { div { class: "loading", "reading Cargo.toml" } }),
+        Some((Ok(None), _)) => {
+            return cx
+                .render(rsx! { div { class: "missing", "no Cargo.toml in the repository root" } })
+        }
+        Some((Ok(Some(crates)), _)) => crates,
+        Some((Err(error), refetch)) => {
+            return cx.render(rsx! {
+                super::error::github_api_error {
+                    error: error,
+                    refetch: refetch,
+                }
+            })
+        }
+    };
+
+    cx.render(rsx! {
+        ul {
+            crates.iter().map(|info| rsx! {
+                crate_info { key: "{info.path}/{info.name}", info: info }
+            })
+        }
+    })
+}
+
+#[inline_props]
+fn crate_info<'a>(cx: Scope, info: &'a CrateInfo) -> Element {
+    let docs_url = info.docs_url();
+    let features = info.features.join(", ");
+    let badge_style = Badge::Package.palette().style();
+    let path = if info.path.is_empty() {
+        "."
+    } else {
+        &info.path
+    };
+
+    cx.render(rsx! {
+        li {
+            class: "crate",
+
+            div {
+                class: "name",
+
+                span { "{info.name}" }
+                span { class: "path", "{path}" }
+                info.published.as_ref().map(|version| rsx! {
+                    span {
+                        class: "badge package",
+                        style: "{badge_style}",
+                        "v{version}"
+                    }
+                })
+                docs_url.map(|docs_url| rsx! {
+                    a {
+                        href: "{docs_url}",
+                        target: "_blank",
+                        rel: "external",
+                        "docs.rs"
+                    }
+                })
+            }
+            info.description.as_ref().map(|description| rsx! {
+                div { class: "about", "{description}" }
+            })
+            div {
+                class: "details",
+
+                info.edition.as_ref().map(|edition| rsx! {
+                    span { "edition {edition}" }
+                })
+                info.rust_version.as_ref().map(|rust_version| rsx! {
+                    span { "rust {rust_version}" }
+                })
+                (!features.is_empty()).then(|| rsx! {
+                    span { "features: {features}" }
+                })
+            }
+        }
+    })
+}
@@ -0,0 +1,56 @@
+use dioxus::prelude::*;
+use time::{macros::format_description, Duration};
+
+use crate::{
+    enrich::Ticket,
+    hook::{
+        use_ci_status::{use_ci_status, CiStatus},
+        use_repos::Repo,
+    },
+    palette::Badge,
+    time::{format_in_zone, use_zone},
+};
+
+#[inline_props]
+pub fn ci_status<'a>(cx: Scope, repo: &'a Repo, ticket: &'a Ticket) -> Element {
+    use_zone(&cx);
+    let run = match use_ci_status(&cx, repo, ticket) {
+        Some(Some(run)) => run,
+        // Repositories without actions simply do not get a badge
+        _ => return None,
+    };
+
+    let (badge, label) = match run.ci_status() {
+        CiStatus::Passing => (Badge::Passing, "passing"),
+        CiStatus::Failing => (Badge::Failing, "failing"),
+        CiStatus::InProgress => (Badge::InProgress, "running"),
+        CiStatus::Neutral => (Badge::Neutral, "inconclusive"),
+    };
+    let style = badge.palette().style();
+    let workflow = run.name.as_deref().unwrap_or("workflow");
+    let started = format_in_zone(
+        run.run_started_at,
+        format_description!("[year]-[month]-[day] [hour]:[minute]"),
+    );
+    let title = match run.ci_status() {
+        CiStatus::InProgress => format!("started {started}"),
+        _ => {
+            let duration = Duration::seconds((run.updated_at - run.run_started_at).whole_seconds());
+
+            format!("ran {started} for {duration}")
+        }
+    };
+
+    cx.render(rsx! {
+        a {
+            class: "badge ci",
+            style: "{style}",
+            href: "{run.html_url}",
+            target: "_blank",
+            rel: "external",
+            title: "{title}",
+
+            "{workflow}: {label}"
+        }
+    })
+}
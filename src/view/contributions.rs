@@ -0,0 +1,100 @@
+use css_colors::{percent, Color};
+use dioxus::prelude::*;
+use time::macros::format_description;
+
+use crate::{
+    colors, config,
+    hook::{
+        use_enrichment::use_enrichment,
+        use_external_contributions::{use_external_contributions, ExternalRepo},
+        use_repo_language::use_repo_language,
+    },
+    palette::Palette,
+    theme::use_theme,
+    time::{format_in_zone, use_zone},
+};
+
+/// Pull requests merged into repositories the accounts do not own
+pub fn external_contributions(cx: Scope) -> Element {
+    cx.render(match use_external_contributions(&cx, config::USERS) {
+        None => rsx! {
+            div { class: "loading", "loading contributions" }
+        },
+        Some((Ok(repos), _)) => rsx! {
+            section {
+                class: "contributions",
+
+                h2 { "contributions" }
+                repos.iter().map(|repo| rsx! {
+                    external_repo { key: "{repo.full_name}", repo: repo }
+                })
+            }
+        },
+        Some((Err(error), refetch)) => rsx! {
+            super::error::github_api_error {
+                error: error,
+                refetch: refetch,
+            }
+        },
+    })
+}
+
+#[inline_props]
+fn external_repo<'a>(cx: Scope, repo: &'a ExternalRepo) -> Element {
+    // The language is only known once the repository itself has been fetched
+    let (id, ticket) = use_enrichment(&cx);
+    colors::use_colors(&cx);
+    let language = use_repo_language(&cx, &repo.full_name, ticket).and_then(Option::as_deref);
+    let color = colors::color_for(language);
+
+    let theme = use_theme(&cx);
+    use_zone(&cx);
+    let style = Palette::themed(color.desaturate(percent(50)), theme).style();
+    let count = repo.pull_requests.len();
+    let plural = if count == 1 { "" } else { "s" };
+
+    cx.render(rsx! {
+        div {
+            id: "{id}",
+            class: "external-repo",
+            style: "{style}",
+
+            div {
+                class: "name",
+
+                a {
+                    href: "{repo.html_url}",
+                    target: "_blank",
+                    rel: "external",
+                    "{repo.full_name}"
+                }
+                span { class: "count", "{count} pull request{plural} merged" }
+            }
+            ul {
+                repo.pull_requests.iter().map(|pull_request| {
+                    let merged = pull_request
+                        .merged_at
+                        .map(|merged_at| {
+                            format_in_zone(merged_at, format_description!("[year]-[month]-[day]"))
+                        })
+                        .unwrap_or_default();
+
+                    rsx! {
+                        li {
+                            key: "{pull_request.html_url}",
+
+                            span { class: "merged", "{merged}" }
+                            a {
+                                href: "{pull_request.html_url}",
+                                target: "_blank",
+                                rel: "external",
+                                title: "by {pull_request.author}",
+                                "#{pull_request.number} {pull_request.title}"
+                            }
+                        }
+                    }
+                })
+            }
+        }
+    })
+}
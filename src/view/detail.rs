@@ -0,0 +1,98 @@
+use dioxus::prelude::*;
+
+use crate::{
+    colors, enrich::Ticket, hook::use_repos::ListedRepo, palette::Palette, theme::use_theme,
+};
+
+/// A page with everything known about a single repository
+#[inline_props]
+pub fn repository_detail<'a>(cx: Scope, repo: &'a ListedRepo) -> Element {
+    colors::use_colors(&cx);
+    let color = repo.color();
+    let repo = &repo.repo;
+    // The repository is the whole page, so its requests always go first
+    let ticket: &Ticket = cx.use_hook(|_| Ticket::visible());
+
+    let theme = use_theme(&cx);
+    let style = Palette::themed(color, theme).style();
+    let language = repo.language.as_deref().unwrap_or("Unknown");
+
+    cx.render(rsx! {
+        article {
+            class: "repo-detail",
+
+            nav {
+                class: "breadcrumbs",
+
+                a { href: "/", "data-route": "", "overview" }
+                " / "
+                a { href: "/{repo.owner.login}", "data-route": "", "{repo.owner.login}" }
+                " / "
+                span { "{repo.name}" }
+            }
+
+            header {
+                class: "summary",
+                style: "{style}",
+
+                h2 {
+                    a {
+                        href: "{repo.html_url}",
+                        target: "_blank",
+                        rel: "external",
+                        title: "view on github",
+
+                        "{repo.full_name}"
+                    }
+                }
+                repo.description.as_ref().map(|description| rsx! { p { "{description}" } })
+                p { super::fork::fork { repo: repo, ticket: ticket } }
+                div {
+                    class: "details",
+
+                    span { "{language}" }
+                    span { "created " super::time::relative_time { time: repo.created_at } }
+                    span { "updated " super::time::relative_time { time: repo.updated_at } }
+                }
+                div {
+                    class: "badges",
+
+                    super::ci::ci_status { repo: repo, ticket: ticket }
+                    super::packages::packages { repo: repo, ticket: ticket }
+                }
+            }
+
+            section {
+                h3 { "activity" }
+                super::sparkline::commit_activity {
+                    repo: repo,
+                    color: color,
+                    ticket: ticket,
+                }
+                super::avatar::contributors { repo: repo, ticket: ticket }
+            }
+
+            section {
+                h3 { "languages" }
+                super::languages::languages { repo: repo, ticket: ticket }
+            }
+
+            section {
+                h3 { "releases" }
+                super::release::releases { repo: repo, ticket: ticket }
+            }
+
+            (repo.language.as_deref() == Some("Rust")).then(|| rsx! {
+                section {
+                    h3 { "crates" }
+                    super::cargo::crate_panel { repo: repo, ticket: ticket }
+                }
+            })
+
+            section {
+                h3 { "readme" }
+                super::readme::readme { repo: repo }
+            }
+        }
+    })
+}
@@ -8,7 +8,11 @@ use futures::StreamExt;
 use gloo_timers::future::IntervalStream;
 use time::{macros::format_description, Duration, OffsetDateTime};
 
-use crate::{gh::GithubApiError, hook::use_repos::RefetchFn, time::now};
+use crate::{
+    gh::GithubApiError,
+    hook::use_repos::RefetchFn,
+    time::{format_in_zone, now, use_zone},
+};
 
 #[inline_props]
 pub fn github_api_error<'a>(
@@ -20,11 +24,20 @@ pub fn github_api_error<'a>(
         GithubApiError::Net(e) => rsx! {
             div { "{e}" }
         },
+        GithubApiError::NotFound { url } => rsx! {
+            div { "{url} could not be found" }
+        },
         GithubApiError::RateLimited { until } => rsx! {
             self::rate_limited {
                 until: *until
             }
         },
+        GithubApiError::BudgetExhausted => rsx! {
+            div { "skipped to stay within github's rate limit" }
+        },
+        GithubApiError::Unexpected { status } => rsx! {
+            div { "github answered with an unexpected status {status}" }
+        },
     };
 
     cx.render(rsx! {
@@ -40,6 +53,7 @@ pub fn github_api_error<'a>(
 
 #[inline_props]
 fn rate_limited(cx: Scope, until: OffsetDateTime) -> Element {
+    use_zone(&cx);
     let elapsed = use_state(&cx, || 0);
     let refresh = use_future(&cx, (), |()| {
         to_owned![elapsed];
@@ -62,9 +76,7 @@ fn rate_limited(cx: Scope, until: OffsetDateTime) -> Element {
         }
     };
 
-    let until = until
-        .format(format_description!("[hour]:[minute]:[second] UTC"))
-        .expect("failed to format date");
+    let until = format_in_zone(*until, format_description!("[hour]:[minute]:[second]"));
 
     let key = crate::gh::AUTH_LOCAL_STORAGE_KEY;
 
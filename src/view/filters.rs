@@ -0,0 +1,108 @@
+use std::collections::BTreeSet;
+
+use dioxus::prelude::*;
+
+use crate::{
+    hook::use_repos::UserRepos,
+    query::{self, use_view_state, Forks, Sort},
+};
+
+/// Search, filter and sort controls for the repository lists
+#[inline_props]
+pub fn filters<'a>(cx: Scope, repos: &'a [(&'static str, UserRepos)]) -> Element {
+    let state = use_view_state(&cx);
+
+    let languages = repos
+        .iter()
+        .flat_map(|(_, repos)| &repos.repos)
+        .filter_map(|repo| repo.repo.language.as_deref())
+        .collect::<BTreeSet<_>>();
+    let all_languages = state.language.is_none();
+
+    cx.render(rsx! {
+        div {
+            class: "filters",
+
+            input {
+                r#type: "search",
+                placeholder: "search repositories",
+                "aria-label": "search repositories",
+                value: "{state.search}",
+                oninput: move |event| {
+                    let search = event.value.clone();
+
+                    query::update(|state| state.search = search);
+                },
+            }
+            select {
+                "aria-label": "language",
+                onchange: move |event| {
+                    let language = Some(event.value.clone()).filter(|language| !language.is_empty());
+
+                    query::update(|state| state.language = language);
+                },
+
+                option { value: "", selected: "{all_languages}", "all languages" }
+                languages.into_iter().map(|language| {
+                    let selected = state.language.as_deref() == Some(language);
+
+                    rsx! {
+                        option {
+                            key: "{language}",
+                            value: "{language}",
+                            selected: "{selected}",
+
+                            "{language}"
+                        }
+                    }
+                })
+            }
+            select {
+                "aria-label": "sort",
+                onchange: move |event| {
+                    if let Some(sort) = Sort::parse(&event.value) {
+                        query::update(|state| state.sort = sort);
+                    }
+                },
+
+                Sort::ALL.into_iter().map(|sort| {
+                    let name = sort.name();
+                    let selected = state.sort == sort;
+
+                    rsx! {
+                        option {
+                            key: "{name}",
+                            value: "{name}",
+                            selected: "{selected}",
+
+                            "sort by {name}"
+                        }
+                    }
+                })
+            }
+            select {
+                "aria-label": "forks",
+                onchange: move |event| {
+                    if let Some(forks) = Forks::parse(&event.value) {
+                        query::update(|state| state.forks = forks);
+                    }
+                },
+
+                Forks::ALL.into_iter().map(|forks| {
+                    let name = forks.name();
+                    let selected = state.forks == forks;
+
+                    rsx! {
+                        option {
+                            key: "{name}",
+                            value: "{name}",
+                            selected: "{selected}",
+
+                            "{name} forks"
+                        }
+                    }
+                })
+            }
+        }
+    })
+}
@@ -0,0 +1,32 @@
+use dioxus::prelude::*;
+
+use crate::{
+    enrich::Ticket,
+    hook::{use_fork::use_fork, use_repos::Repo},
+};
+
+/// Where a forked repository came from and how far it has diverged
+#[inline_props]
+pub fn fork<'a>(cx: Scope, repo: &'a Repo, ticket: &'a Ticket) -> Element {
+    let fork = use_fork(&cx, repo, ticket)?.as_ref()?;
+
+    let divergence = match (fork.ahead_by, fork.behind_by) {
+        (Some(ahead_by), Some(behind_by)) => {
+            format!(" ({ahead_by} ahead, {behind_by} behind)")
+        }
+        _ => String::new(),
+    };
+
+    cx.render(rsx! {
+        span {
+            class: "fork",
+
+            "Forked from "
+            a {
+                href: "{fork.parent.html_url}",
+                "{fork.parent.full_name}"
+            }
+            "{divergence}"
+        }
+    })
+}
@@ -0,0 +1,102 @@
+use css_colors::{percent, Color};
+use dioxus::prelude::*;
+
+use crate::{
+    colors,
+    hook::{use_gist_preview::use_gist_preview, use_repos::Gist},
+    palette::Palette,
+    theme::use_theme,
+};
+
+#[inline_props]
+pub fn gists<'a>(cx: Scope, gists: &'a [Gist]) -> Element {
+    if gists.is_empty() {
+        return None;
+    }
+
+    cx.render(rsx! {
+        div {
+            class: "gists",
+
+            h3 { "gists" }
+            gists.iter().map(|gist| rsx! {
+                self::gist { key: "{gist.node_id}", gist: gist }
+            })
+        }
+    })
+}
+
+#[inline_props]
+fn gist<'a>(cx: Scope, gist: &'a Gist) -> Element {
+    colors::use_colors(&cx);
+    let theme = use_theme(&cx);
+    let show_preview = use_state(&cx, || false);
+    let preview_label = if *show_preview.get() {
+        "hide preview"
+    } else {
+        "show preview"
+    };
+
+    cx.render(rsx! {
+        div {
+            class: "gist",
+
+            div {
+                class: "files",
+
+                gist.files.values().map(|file| {
+                    let color = colors::color_for(file.language.as_deref());
+                    let style = Palette::themed(color.desaturate(percent(50)), theme).style();
+                    let language = file.language.as_deref().unwrap_or("Unknown");
+
+                    rsx! {
+                        a {
+                            key: "{file.filename}",
+                            class: "file",
+                            style: "{style}",
+                            href: "{gist.html_url}",
+                            target: "_blank",
+                            rel: "external",
+                            title: "{language}, {file.size} bytes",
+
+                            "{file.filename}"
+                        }
+                    }
+                })
+            }
+            gist.description.as_ref().filter(|description| !description.is_empty()).map(|description| rsx! {
+                div { class: "description", "{description}" }
+            })
+            button {
+                class: "expand",
+                onclick: move |_| show_preview.modify(|show| !show),
+
+                "{preview_label}"
+            }
+            show_preview.get().then(|| rsx! {
+                gist_preview { gist: gist }
+            })
+        }
+    })
+}
+
+#[inline_props]
+fn gist_preview<'a>(cx: Scope, gist: &'a Gist) -> Element {
+    cx.render(match use_gist_preview(&cx, gist) {
+        None => rsx! {
+            div { class: "preview loading", "loading preview" }
+        },
+        Some((Ok(Some(preview)), _)) => rsx! {
+            pre { class: "preview", code { "{preview}" } }
+        },
+        Some((Ok(None), _)) => rsx! {
+            div { class: "preview missing", "this gist is empty" }
+        },
+        Some((Err(error), refetch)) => rsx! {
+            super::error::github_api_error {
+                error: error,
+                refetch: refetch,
+            }
+        },
+    })
+}
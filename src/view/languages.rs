@@ -0,0 +1,70 @@
+use dioxus::prelude::*;
+
+use crate::{
+    colors,
+    enrich::Ticket,
+    hook::{use_languages::use_languages, use_repos::Repo},
+};
+
+/// The share of each language in a repository, as a bar and a legend
+#[inline_props]
+pub fn languages<'a>(cx: Scope, repo: &'a Repo, ticket: &'a Ticket) -> Element {
+    colors::use_colors(&cx);
+    let languages = match use_languages(&cx, repo, ticket) {
+        None => {
+            return cx.render(rsx! {
+                div { class: "languages loading", "loading languages" }
+            })
+        }
+        Some((Ok(languages), _)) if languages.is_empty() => {
+            return cx.render(rsx! {
+                div { class: "languages missing", "no code detected" }
+            })
+        }
+        Some((Ok(languages), _)) => languages,
+        Some((Err(error), refetch)) => {
+            return cx.render(rsx! {
+                super::error::github_api_error {
+                    error: error,
+                    refetch: refetch,
+                }
+            })
+        }
+    };
+
+    let total = languages.iter().map(|(_, bytes)| bytes).sum::<u64>() as f64;
+    let languages = languages.iter().map(move |(name, bytes)| {
+        let share = *bytes as f64 / total * 100.0;
+        let color = colors::color_for(Some(name.as_str()));
+
+        (name, share, color)
+    });
+
+    cx.render(rsx! {
+        div {
+            class: "languages",
+
+            div {
+                class: "bar",
+
+                languages.clone().map(|(name, share, color)| rsx! {
+                    span {
+                        key: "{name}",
+                        style: "width: {share}%; background-color: {color};",
+                        title: "{name} {share:.1}%",
+                    }
+                })
+            }
+            ul {
+                languages.map(|(name, share, color)| rsx! {
+                    li {
+                        key: "{name}",
+
+                        span { class: "swatch", style: "background-color: {color};" }
+                        "{name} {share:.1}%"
+                    }
+                })
+            }
+        }
+    })
+}
@@ -0,0 +1,22 @@
+use dioxus::prelude::*;
+
+use crate::route;
+
+pub fn not_found(cx: Scope) -> Element {
+    let path = route::current().path();
+
+    cx.render(rsx! {
+        section {
+            class: "not-found",
+
+            h2 { "page not found" }
+            p { "there is nothing at {path}" }
+            a {
+                href: "/",
+                "data-route": "",
+
+                "back to the overview"
+            }
+        }
+    })
+}
@@ -0,0 +1,67 @@
+use dioxus::prelude::*;
+
+use crate::{
+    enrich::Ticket,
+    hook::{use_packages::use_packages, use_repos::Repo},
+    palette::Badge,
+};
+
+/// Format a count compactly, ie `12.3k`
+fn compact(count: u64) -> String {
+    // Rounded first, so that counts just short of a million do not show as `1000.0k`
+    let thousands = (count as f64 / 100.0).round() / 10.0;
+
+    if count < 1_000 {
+        count.to_string()
+    } else if thousands < 1_000.0 {
+        format!("{thousands:.1}k")
+    } else {
+        format!("{:.1}M", count as f64 / 1e6)
+    }
+}
+
+#[inline_props]
+pub fn packages<'a>(cx: Scope, repo: &'a Repo, ticket: &'a Ticket) -> Element {
+    let packages = use_packages(&cx, repo, ticket)?;
+    let style = Badge::Package.palette().style();
+
+    cx.render(rsx! {
+        packages.iter().map(|package| {
+            let registry = package.registry.name();
+            let url = package.url();
+            let downloads = package
+                .downloads
+                .map(|downloads| format!(", {} downloads", compact(downloads)))
+                .unwrap_or_default();
+
+            rsx! {
+                a {
+                    key: "{registry}",
+                    class: "badge package",
+                    style: "{style}",
+                    href: "{url}",
+                    target: "_blank",
+                    rel: "external",
+                    title: "{package.name} on {registry}{downloads}",
+
+                    "{registry} v{package.version}"
+                }
+            }
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compact;
+
+    #[test]
+    fn compact_rounds_before_choosing_the_unit() {
+        assert_eq!(compact(999), "999");
+        assert_eq!(compact(1_000), "1.0k");
+        assert_eq!(compact(12_345), "12.3k");
+        assert_eq!(compact(999_949), "999.9k");
+        assert_eq!(compact(999_950), "1.0M");
+        assert_eq!(compact(2_500_000), "2.5M");
+    }
+}
@@ -0,0 +1,166 @@
+use dioxus::prelude::*;
+
+use crate::{
+    config::{self, PROFILE_LINKS, PROFILE_OVERRIDES},
+    hook::use_profile::{use_profile, Profile},
+    time::now,
+};
+
+/// Strip the scheme off of a url for display
+fn display_url(url: &str) -> &str {
+    url.trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_end_matches('/')
+}
+
+/// Github does not require a scheme on the website of a profile, so add one
+/// to keep the link from being relative to this page
+fn absolute_url(url: &str) -> String {
+    if url.contains("://") {
+        url.to_string()
+    } else {
+        format!("https://{url}")
+    }
+}
+
+/// The title card contents, generated from the github profile of the configured account
+pub fn profile(cx: Scope) -> Element {
+    let profile = match use_profile(&cx, config::PROFILE_USER) {
+        Some(Ok(profile)) => Some(profile),
+        // Fall back to the configured overrides while loading or if the profile is unavailable
+        None | Some(Err(_)) => None,
+    };
+
+    let name = PROFILE_OVERRIDES
+        .name
+        .or_else(|| profile.and_then(|profile| profile.name.as_deref()))
+        .unwrap_or(config::PROFILE_USER);
+    let email = PROFILE_OVERRIDES
+        .email
+        .or_else(|| profile.and_then(|profile| profile.email.as_deref()));
+    let bio = PROFILE_OVERRIDES
+        .bio
+        .or_else(|| profile.and_then(|profile| profile.bio.as_deref()));
+    let blog = PROFILE_OVERRIDES.blog.or_else(|| {
+        profile
+            .and_then(|profile| profile.blog.as_deref())
+            .filter(|blog| !blog.is_empty())
+    });
+
+    cx.render(rsx! {
+        div {
+            class: "title",
+
+            profile.map(|profile| rsx! {
+                img {
+                    class: "profile-avatar",
+                    src: "{profile.avatar_url}",
+                    alt: "{name}'s avatar",
+                }
+            })
+            div {
+                class: "main",
+                "{name}"
+            }
+            bio.map(|bio| rsx! {
+                div { class: "bio", "{bio}" }
+            })
+            profile.map(|profile| rsx! { profile_details { profile: profile } })
+            email.map(|email| rsx! {
+                div {
+                    a {
+                        href: "mailto:{email}",
+                        title: "{name}'s email",
+                        target: "_blank",
+                        rel: "author",
+                        "{email}"
+                    }
+                }
+            })
+            blog.map(|blog| {
+                let text = display_url(blog);
+                let href = absolute_url(blog);
+
+                rsx! {
+                    div {
+                        a {
+                            href: "{href}",
+                            title: "{name}'s website",
+                            target: "_blank",
+                            rel: "author",
+                            "{text}"
+                        }
+                    }
+                }
+            })
+            PROFILE_LINKS.iter().map(|link| rsx! {
+                div {
+                    key: "{link.href}",
+
+                    a {
+                        href: "{link.href}",
+                        title: "{name}'s {link.title}",
+                        target: "_self",
+                        rel: "{link.rel}",
+                        "{link.text}"
+                    }
+                }
+            })
+            profile.map(|profile| {
+                let text = display_url(&profile.html_url);
+
+                rsx! {
+                    div {
+                        a {
+                            href: "{profile.html_url}",
+                            title: "{name}'s github",
+                            target: "_blank",
+                            rel: "external",
+                            "{text}"
+                        }
+                    }
+                }
+            })
+            profile.map(|profile| rsx! {
+                profile.social_accounts.iter().map(|account| {
+                    let text = display_url(&account.url);
+
+                    rsx! {
+                        div {
+                            key: "{account.url}",
+
+                            a {
+                                href: "{account.url}",
+                                title: "{name}'s {account.provider}",
+                                target: "_blank",
+                                rel: "me",
+                                "{text}"
+                            }
+                        }
+                    }
+                })
+            })
+        }
+    })
+}
+
+#[inline_props]
+fn profile_details<'a>(cx: Scope, profile: &'a Profile) -> Element {
+    let years = match (now() - profile.created_at).whole_days() / 365 {
+        0 => "less than a year".to_string(),
+        1 => "a year".to_string(),
+        years => format!("{years} years"),
+    };
+
+    cx.render(rsx! {
+        div {
+            class: "details",
+
+            profile.location.as_ref().map(|location| rsx! { span { "{location}" } })
+            profile.company.as_ref().map(|company| rsx! { span { "{company}" } })
+            span { "{profile.followers} followers" }
+            span { "{profile.following} following" }
+            span { "on github for {years}" }
+        }
+    })
+}
@@ -0,0 +1,33 @@
+use dioxus::prelude::*;
+
+use crate::hook::{use_readme::use_readme, use_repos::Repo};
+
+#[inline_props]
+pub fn readme<'a>(cx: Scope, repo: &'a Repo) -> Element {
+    let readme = use_readme(&cx, repo);
+
+    cx.render(match readme {
+        None => rsx! {
+            div { class: "readme loading", "loading readme" }
+        },
+        Some((Ok(Some(html)), _)) => rsx! {
+            div {
+                class: "readme",
+                dangerous_inner_html: "{html}",
+            }
+        },
+        Some((Ok(None), _)) => rsx! {
+            div { class: "readme missing", "this repository has no readme" }
+        },
+        Some((Err(error), refetch)) => rsx! {
+            div {
+                class: "readme",
+
+                super::error::github_api_error {
+                    error: error,
+                    refetch: refetch,
+                }
+            }
+        },
+    })
+}
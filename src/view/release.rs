@@ -0,0 +1,126 @@
+use dioxus::prelude::*;
+
+use crate::{
+    enrich::Ticket,
+    hook::{
+        use_latest_release::use_latest_release,
+        use_releases::use_releases,
+        use_repos::{Release, Repo},
+    },
+    markdown::{self, RepoLinks},
+    palette::Badge,
+};
+
+#[inline_props]
+pub fn release<'a>(cx: Scope, repo: &'a Repo, release: &'a Release) -> Element {
+    let show_notes = use_state(&cx, || false);
+
+    let body = release
+        .body
+        .as_deref()
+        .filter(|body| !body.trim().is_empty());
+
+    // Only render the notes once they are asked for
+    let notes = body.filter(|_| *show_notes.get()).map(|body| {
+        let links = RepoLinks::new(&repo.full_name, &repo.default_branch, "");
+
+        markdown::render(body, Some(&links))
+    });
+
+    let prerelease_style = Badge::Prerelease.palette().style();
+    let title = release.name.as_deref().unwrap_or(&release.tag_name);
+    let notes_label = if *show_notes.get() {
+        "hide release notes"
+    } else {
+        "release notes"
+    };
+
+    cx.render(rsx! {
+        div {
+            class: "release",
+
+            a {
+                href: "{release.html_url}",
+                target: "_blank",
+                rel: "external",
+                title: "{title}",
+
+                "{release.tag_name}"
+            }
+            release.prerelease.then(|| rsx! {
+                span {
+                    class: "badge prerelease",
+                    style: "{prerelease_style}",
+                    "prerelease"
+                }
+            })
+            release.published_at.map(|published_at| rsx! {
+                span {
+                    class: "published",
+
+                    "released "
+                    super::time::relative_time { time: published_at }
+                }
+            })
+            body.is_some().then(|| rsx! {
+                button {
+                    class: "expand",
+                    onclick: move |_| show_notes.modify(|show| !show),
+
+                    "{notes_label}"
+                }
+            })
+            notes.map(|notes| rsx! {
+                div {
+                    class: "notes",
+                    dangerous_inner_html: "{notes}",
+                }
+            })
+        }
+    })
+}
+
+/// The latest release of a repository, if it has one
+#[inline_props]
+pub fn latest_release<'a>(cx: Scope, repo: &'a Repo, ticket: &'a Ticket) -> Element {
+    let release = use_latest_release(&cx, repo, ticket)?.as_ref()?;
+
+    cx.render(rsx! {
+        self::release {
+            repo: repo,
+            release: release,
+        }
+    })
+}
+
+/// The most recent releases of a repository
+#[inline_props]
+pub fn releases<'a>(cx: Scope, repo: &'a Repo, ticket: &'a Ticket) -> Element {
+    cx.render(match use_releases(&cx, repo, ticket) {
+        None => rsx! {
+            div { class: "releases loading", "loading releases" }
+        },
+        Some((Ok(releases), _)) if releases.is_empty() => rsx! {
+            div { class: "releases missing", "this repository has no releases" }
+        },
+        Some((Ok(releases), _)) => rsx! {
+            div {
+                class: "releases",
+
+                releases.iter().map(|release| rsx! {
+                    self::release {
+                        key: "{release.tag_name}",
+                        repo: repo,
+                        release: release,
+                    }
+                })
+            }
+        },
+        Some((Err(error), refetch)) => rsx! {
+            super::error::github_api_error {
+                error: error,
+                refetch: refetch,
+            }
+        },
+    })
+}
@@ -1,28 +1,44 @@
 use css_colors::{percent, Color};
 use dioxus::prelude::*;
 
-use crate::hook::use_repos::RepoAndColor;
+use crate::{
+    colors, config,
+    hook::{use_enrichment::use_enrichment, use_repos::ListedRepo},
+    palette::Palette,
+    query,
+    theme::use_theme,
+};
 
 #[inline_props]
-pub fn repository<'a>(cx: Scope, repo: &'a RepoAndColor, saturate: bool) -> Element {
-    let RepoAndColor { repo, color } = repo;
-
-    let color = color
-        .map(|color| {
-            if *saturate {
-                color
-            } else {
-                color.desaturate(percent(50))
-            }
-        })
-        .map(|color| color.to_string())
-        .unwrap_or_else(|| "default".to_string());
+pub fn repository<'a>(cx: Scope, repo: &'a ListedRepo, saturate: bool, expanded: bool) -> Element {
+    colors::use_colors(&cx);
+    let color = repo.color();
+    let repo = &repo.repo;
+    let (id, ticket) = use_enrichment(&cx);
+
+    let theme = use_theme(&cx);
+    let style = Palette::themed(
+        if *saturate {
+            color
+        } else {
+            color.desaturate(percent(50))
+        },
+        theme,
+    )
+    .style();
     let language = repo.language.as_deref().unwrap_or("Unknown");
 
+    let readme_label = if *expanded {
+        "hide readme"
+    } else {
+        "show readme"
+    };
+
     cx.render(rsx! {
         div {
+            id: "{id}",
             class: "repo",
-            style: "background-color: {color}",
+            style: "{style}",
 
             div {
                 class: "description",
@@ -42,15 +58,51 @@ pub fn repository<'a>(cx: Scope, repo: &'a RepoAndColor, saturate: bool) -> Elem
                     }
                 }
                 repo.description.as_ref().map(|description| rsx!{ span { "Description: {description}" } })
+                super::fork::fork { repo: repo, ticket: ticket }
                 span { "Lang: {language}" }
-                span { "Created: {repo.created_at}" }
-                span { "Updated: {repo.updated_at}" }
+                a {
+                    class: "details",
+                    href: "/{repo.full_name}",
+                    "data-route": "",
+
+                    "details"
+                }
+                span { "Created " super::time::relative_time { time: repo.created_at } }
+                span { "Updated " super::time::relative_time { time: repo.updated_at } }
             }
 
-            details {
-                summary { "raw..." }
-                pre { "{repo:#?}" }
+            div {
+                class: "badges",
+
+                super::ci::ci_status { repo: repo, ticket: ticket }
+                super::packages::packages { repo: repo, ticket: ticket }
+            }
+
+            super::release::latest_release { repo: repo, ticket: ticket }
+
+            (repo.language.as_deref() == Some("Rust")).then(|| rsx! {
+                super::cargo::crate_panel { repo: repo, ticket: ticket }
+            })
+
+            super::sparkline::commit_activity {
+                repo: repo,
+                color: color,
+                ticket: ticket,
+            }
+
+            config::SHOW_CONTRIBUTORS.then(|| rsx! {
+                super::avatar::contributors { repo: repo, ticket: ticket }
+            })
+
+            button {
+                class: "expand",
+                onclick: move |_| query::toggle_expanded(&repo.full_name),
+
+                "{readme_label}"
             }
+            expanded.then(|| rsx! {
+                super::readme::readme { repo: repo }
+            })
         }
     })
 }
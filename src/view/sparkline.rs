@@ -0,0 +1,70 @@
+use css_colors::RGB;
+use dioxus::prelude::*;
+
+use crate::{
+    enrich::Ticket,
+    gh::GithubApiError,
+    hook::{
+        use_commit_activity::{use_commit_activity, Activity},
+        use_repos::Repo,
+    },
+};
+
+const WIDTH: f64 = 104.0;
+const HEIGHT: f64 = 24.0;
+
+/// Draw the values as a line chart, scaled to fit an inline svg
+fn sparkline_svg(values: &[u32], color: &str) -> String {
+    let max = values.iter().copied().max().unwrap_or(0).max(1) as f64;
+    let step = WIDTH / values.len().saturating_sub(1).max(1) as f64;
+
+    let points = values
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| {
+            let x = i as f64 * step;
+            // Leave room for the stroke at the top and bottom
+            let y = HEIGHT - 1.0 - (value as f64 / max) * (HEIGHT - 2.0);
+
+            format!("{x:.1},{y:.1}")
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    format!(
+        r#"<svg viewBox="0 0 {WIDTH} {HEIGHT}" width="{WIDTH}" height="{HEIGHT}" preserveAspectRatio="none"><polyline points="{points}" fill="none" stroke="{color}" stroke-width="1.5" stroke-linejoin="round" stroke-linecap="round"/></svg>"#
+    )
+}
+
+#[inline_props]
+pub fn commit_activity<'a>(cx: Scope, repo: &'a Repo, color: RGB, ticket: &'a Ticket) -> Element {
+    let activity = use_commit_activity(&cx, repo, ticket);
+
+    cx.render(match activity {
+        None => rsx! {
+            span { class: "sparkline loading", "computing activity" }
+        },
+        Some(Ok(Activity::Weeks(weeks))) => {
+            let total: u32 = weeks.iter().sum();
+            let svg = sparkline_svg(weeks, &color.to_string());
+
+            rsx! {
+                span {
+                    class: "sparkline",
+                    title: "{total} commits in the last year",
+                    dangerous_inner_html: "{svg}",
+                }
+            }
+        }
+        Some(Err(GithubApiError::BudgetExhausted)) => rsx! {
+            span { class: "sparkline missing", "activity skipped" }
+        },
+        Some(Ok(Activity::Computing)) => rsx! {
+            span { class: "sparkline missing", "activity not computed yet" }
+        },
+        // Activity is a nice to have, so do not bother showing why it is missing
+        Some(Ok(Activity::Missing) | Err(_)) => rsx! {
+            span { class: "sparkline missing", "no recent activity" }
+        },
+    })
+}
@@ -0,0 +1,33 @@
+use dioxus::prelude::*;
+
+use crate::theme::{self, use_theme, Preference};
+
+/// Switch between the light, dark and system themes
+pub fn theme_toggle(cx: Scope) -> Element {
+    use_theme(&cx);
+    let current = theme::preference();
+
+    cx.render(rsx! {
+        div {
+            class: "theme-toggle",
+            role: "group",
+            "aria-label": "theme",
+
+            Preference::ALL.into_iter().map(|preference| {
+                let name = preference.name();
+                let selected = if preference == current { "selected" } else { "" };
+
+                rsx! {
+                    button {
+                        key: "{name}",
+                        class: "{selected}",
+                        "aria-pressed": "{preference == current}",
+                        onclick: move |_| theme::set_preference(preference),
+
+                        "{name}"
+                    }
+                }
+            })
+        }
+    })
+}
@@ -0,0 +1,55 @@
+use dioxus::prelude::*;
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use crate::time::{exact, now, relative, set_zone, use_ticker, use_zone, zone_name, Zone};
+
+/// How long ago a time was, kept up to date, with the exact time on hover
+#[inline_props]
+pub fn relative_time(cx: Scope, time: OffsetDateTime) -> Element {
+    use_ticker(&cx);
+    use_zone(&cx);
+
+    let relative = relative(*time);
+    let exact = exact(*time);
+    let datetime = time.format(&Rfc3339).expect("failed to format time");
+
+    cx.render(rsx! {
+        time {
+            datetime: "{datetime}",
+            title: "{exact}",
+
+            "{relative}"
+        }
+    })
+}
+
+/// Switch between showing times in the browser's time zone and in utc
+pub fn zone_toggle(cx: Scope) -> Element {
+    let current = use_zone(&cx);
+    let title = zone_name(now());
+
+    cx.render(rsx! {
+        div {
+            class: "zone-toggle",
+            role: "group",
+            "aria-label": "time zone",
+            title: "times are shown in {title}",
+
+            Zone::ALL.into_iter().map(|zone| {
+                let name = zone.name();
+                let selected = if zone == current { "selected" } else { "" };
+
+                rsx! {
+                    button {
+                        key: "{name}",
+                        class: "{selected}",
+                        "aria-pressed": "{zone == current}",
+                        onclick: move |_| set_zone(zone),
+
+                        "{name}"
+                    }
+                }
+            })
+        }
+    })
+}
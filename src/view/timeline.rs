@@ -0,0 +1,65 @@
+use dioxus::prelude::*;
+
+use crate::{
+    config,
+    hook::use_events::{entries, use_events, Events},
+};
+
+/// Recent public activity of every configured account
+pub fn timeline(cx: Scope) -> Element {
+    let Events {
+        events,
+        state,
+        next_page,
+        refetch,
+    } = use_events(&cx, config::USERS);
+    let entries = entries(&events);
+
+    let footer = match state {
+        None => rsx! {
+            div { class: "loading", "loading activity" }
+        },
+        Some(Ok(true)) => rsx! {
+            button {
+                class: "expand",
+                onclick: move |_| next_page(),
+
+                "load more"
+            }
+        },
+        Some(Ok(false)) => rsx! {
+            div { class: "end", "that's everything github remembers" }
+        },
+        Some(Err(error)) => rsx! {
+            super::error::github_api_error {
+                error: error,
+                refetch: refetch,
+            }
+        },
+    };
+
+    cx.render(rsx! {
+        section {
+            class: "timeline",
+
+            h2 { "recent activity" }
+            ol {
+                entries.iter().map(|entry| rsx! {
+                    li {
+                        key: "{entry.url}{entry.created_at}",
+
+                        span { class: "when", super::time::relative_time { time: entry.created_at } }
+                        span { class: "actor", "{entry.actor}" }
+                        a {
+                            href: "{entry.url}",
+                            target: "_blank",
+                            rel: "external",
+                            "{entry.description}"
+                        }
+                    }
+                })
+            }
+            footer
+        }
+    })
+}
@@ -0,0 +1,62 @@
+use dioxus::prelude::*;
+
+use crate::{hook::use_repos::UserRepos, query::use_view_state};
+
+/// Everything belonging to one account
+#[inline_props]
+pub fn user<'a>(cx: Scope, user: &'static str, repos: &'a UserRepos) -> Element {
+    let state = use_view_state(&cx);
+    // Pinned repositories keep the order they are pinned in
+    let pinned = repos
+        .pinned()
+        .filter(|repo| state.matches(&repo.repo))
+        .collect::<Vec<_>>();
+    let listed = state.apply(repos.repos.iter());
+
+    cx.render(rsx! {
+        section {
+            class: "user",
+
+            h2 {
+                class: "username",
+
+                super::avatar::avatar {
+                    avatar_url: &repos.account.avatar_url,
+                    login: &repos.account.login,
+                    title: repos.account.login.clone(),
+                }
+                a {
+                    href: "/{user}",
+                    "data-route": "",
+
+                    "{user}"
+                }
+            }
+            super::calendar::contribution_calendar { user: *user }
+            (!pinned.is_empty()).then(|| rsx! {
+                div {
+                    class: "pinned",
+
+                    h3 { "pinned" }
+                    pinned.into_iter().map(|repo| {
+                        let expanded = state.is_expanded(&repo.repo.full_name);
+
+                        rsx! {
+                            super::repos::repository {
+                                key: "{repo.repo.node_id}",
+                                repo: repo
+                                saturate: true,
+                                expanded: expanded,
+                            }
+                        }
+                    })
+                }
+            })
+            listed.is_empty().then(|| rsx! {
+                div { class: "no-matches", "no repositories match the filters" }
+            })
+            super::window::repository_list { repos: listed }
+            super::gists::gists { gists: &repos.gists }
+        }
+    })
+}
@@ -0,0 +1,45 @@
+use dioxus::prelude::*;
+
+use crate::{
+    config,
+    hook::{use_repos::ListedRepo, use_windowing::use_windowing},
+    query::use_view_state,
+};
+
+/// A list of repository cards, only rendering those near the viewport once it gets long
+#[inline_props]
+pub fn repository_list<'a>(cx: Scope, repos: Vec<&'a ListedRepo>) -> Element {
+    let state = use_view_state(&cx);
+    let (id, window) = use_windowing(
+        &cx,
+        repos.len() > config::WINDOW_THRESHOLD,
+        repos.iter().map(|repo| repo.repo.node_id.as_str()),
+    );
+
+    cx.render(rsx! {
+        div {
+            id: "{id}",
+            class: "repo-list",
+
+            div { class: "spacer", style: "height: {window.before}px;" }
+            repos[window.start..window.end].iter().copied().map(|repo| {
+                let expanded = state.is_expanded(&repo.repo.full_name);
+
+                rsx! {
+                    div {
+                        key: "{repo.repo.node_id}",
+                        class: "repo-list-item",
+                        "data-key": "{repo.repo.node_id}",
+
+                        super::repos::repository {
+                            repo: repo,
+                            saturate: false,
+                            expanded: expanded,
+                        }
+                    }
+                }
+            })
+            div { class: "spacer", style: "height: {window.after}px;" }
+        }
+    })
+}